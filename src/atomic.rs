@@ -1,6 +1,14 @@
 use crate::{False, True};
 use crate::{IsAtomic, SameAs};
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::sync::atomic::Ordering;
+#[cfg(all(feature = "std", not(feature = "alloc"), target_has_atomic = "ptr"))]
+use std::sync::Arc;
 
 /// A trait for types that have an equivalent atomic type.
 pub trait IntoAtomic: IsAtomic<Atomic = False> + Sized + Send + Sync {
@@ -23,6 +31,95 @@ pub trait IntoAtomic: IsAtomic<Atomic = False> + Sized + Send + Sync {
     fn get_mut_array<const N: usize>(this: &mut [Self::AtomicType; N]) -> &mut [Self; N];
     /// Converts a mutable reference to an array of non-atomic values into one of atomic values.
     fn from_mut_array<const N: usize>(this: &mut [Self; N]) -> &mut [Self::AtomicType; N];
+
+    /// Converts a vector of non-atomic values into a vector of atomic values.
+    ///
+    /// No copy is made: the vector reuses the same allocation, as the
+    /// [`SameAs`] trait guarantees that the two types have the same layout.
+    /// Size and alignment are also checked at compile time, so using the
+    /// method with types whose layout differs (e.g., the atomic types of the
+    /// [loom backend](crate::sync)) is a compile-time error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use common_traits::{Atomic, IntoAtomic};
+    /// use core::sync::atomic::Ordering;
+    ///
+    /// let data = vec![1_u64, 2, 3];
+    /// let ptr = data.as_ptr() as usize;
+    /// let atomic = u64::into_atomic_vec(data);
+    /// std::thread::scope(|s| {
+    ///     s.spawn(|| atomic[0].store(4, Ordering::Relaxed));
+    /// });
+    /// let data = u64::from_atomic_vec(atomic);
+    /// assert_eq!(data, vec![4, 2, 3]);
+    /// assert_eq!(data.as_ptr() as usize, ptr);
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[inline(always)]
+    fn into_atomic_vec(this: Vec<Self>) -> Vec<Self::AtomicType> {
+        let () = SameLayout::<Self, Self::AtomicType>::CHECK;
+        let mut this = core::mem::ManuallyDrop::new(this);
+        // SAFETY: SameAs guarantees that the types have the same layout, and
+        // size and alignment are checked above
+        unsafe {
+            Vec::from_raw_parts(
+                this.as_mut_ptr() as *mut Self::AtomicType,
+                this.len(),
+                this.capacity(),
+            )
+        }
+    }
+
+    /// Converts a vector of atomic values into a vector of non-atomic values.
+    ///
+    /// No copy is made: the vector reuses the same allocation, as the
+    /// [`SameAs`] trait guarantees that the two types have the same layout.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[inline(always)]
+    fn from_atomic_vec(this: Vec<Self::AtomicType>) -> Vec<Self> {
+        let () = SameLayout::<Self::AtomicType, Self>::CHECK;
+        let mut this = core::mem::ManuallyDrop::new(this);
+        // SAFETY: as in into_atomic_vec
+        unsafe { Vec::from_raw_parts(this.as_mut_ptr() as *mut Self, this.len(), this.capacity()) }
+    }
+
+    /// Converts a boxed slice of non-atomic values into a boxed slice of
+    /// atomic values.
+    ///
+    /// No copy is made, as in [`into_atomic_vec`](IntoAtomic::into_atomic_vec).
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[inline(always)]
+    fn into_atomic_boxed_slice(this: Box<[Self]>) -> Box<[Self::AtomicType]> {
+        let () = SameLayout::<Self, Self::AtomicType>::CHECK;
+        // SAFETY: as in into_atomic_vec
+        unsafe { Box::from_raw(Box::into_raw(this) as *mut [Self::AtomicType]) }
+    }
+
+    /// Converts a boxed slice of atomic values into a boxed slice of
+    /// non-atomic values.
+    ///
+    /// No copy is made, as in [`from_atomic_vec`](IntoAtomic::from_atomic_vec).
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[inline(always)]
+    fn from_atomic_boxed_slice(this: Box<[Self::AtomicType]>) -> Box<[Self]> {
+        let () = SameLayout::<Self::AtomicType, Self>::CHECK;
+        // SAFETY: as in into_atomic_vec
+        unsafe { Box::from_raw(Box::into_raw(this) as *mut [Self]) }
+    }
+
+    /// Converts a vector of non-atomic values into a reference-counted slice
+    /// of atomic values, which can be shared among threads.
+    ///
+    /// Differently from [`into_atomic_vec`](IntoAtomic::into_atomic_vec), the
+    /// values are copied once, as an [`Arc`] stores its reference counts in
+    /// the same allocation of the data.
+    #[cfg(all(any(feature = "alloc", feature = "std"), target_has_atomic = "ptr"))]
+    #[inline(always)]
+    fn into_atomic_arc(this: Vec<Self>) -> Arc<[Self::AtomicType]> {
+        Arc::from(Self::into_atomic_vec(this))
+    }
 }
 
 /// Values that can be atomically read and written.
@@ -79,6 +176,54 @@ pub trait Atomic: IsAtomic<Atomic = True> + Sized + Send + Sync {
     /// Returns a mutable array of atomic values from a mutable array of non-atomic values.
     fn from_mut_array<const N: usize>(this: &mut [Self::NonAtomicType; N]) -> &mut [Self; N];
 
+    /// Returns a mutable pointer to the underlying value.
+    ///
+    /// Doing non-atomic reads and writes through the pointer can be a data
+    /// race. The method is mostly useful to pass the atomic to foreign code.
+    #[inline(always)]
+    fn as_ptr(&self) -> *mut Self::NonAtomicType {
+        #[cfg(loom)]
+        crate::sync::check_layout();
+        // SAFETY: SameAs guarantees that the types have the same layout, and
+        // all atomic types are based on interior mutability
+        self as *const Self as *mut Self::NonAtomicType
+    }
+
+    /// Returns a reference to an atomic from a pointer to a non-atomic value.
+    ///
+    /// This makes it possible to place atomics over memory that is not
+    /// managed by Rust, such as a segment shared among processes.
+    ///
+    /// # Safety
+    ///
+    /// - `ptr` must be aligned to `align_of::<Self>()`, which might be larger
+    ///   than `align_of::<Self::NonAtomicType>()`: for example,
+    ///   [`AtomicU64`](core::sync::atomic::AtomicU64) is aligned to eight
+    ///   bytes even on 32-bit platforms where [`u64`] is aligned to four, and
+    ///   an [`AtomicCell`](crate::AtomicCell) has the alignment of the
+    ///   underlying atomic integer. Pointers obtained from references to
+    ///   `Self::NonAtomicType` are thus not necessarily aligned. The
+    ///   alignment is checked only in debug builds.
+    /// - `ptr` must be [valid](core::ptr#safety) for reads and writes for
+    ///   the whole lifetime `'a`.
+    /// - During the lifetime `'a`, the value must be accessed only through
+    ///   atomic operations, except for non-overlapping non-atomic accesses
+    ///   with a happens-before relationship, as in [`AtomicU64::from_ptr`].
+    ///
+    /// [`AtomicU64::from_ptr`]: core::sync::atomic::AtomicU64::from_ptr
+    #[inline(always)]
+    unsafe fn from_ptr<'a>(ptr: *mut Self::NonAtomicType) -> &'a Self {
+        #[cfg(loom)]
+        crate::sync::check_layout();
+        debug_assert!(
+            ptr.cast::<Self>().is_aligned(),
+            "the pointer is not aligned to the alignment of the atomic type"
+        );
+        // SAFETY: SameAs guarantees that the types have the same layout,
+        // and the caller guarantees alignment and the rest
+        unsafe { &*(ptr as *const Self) }
+    }
+
     /// Stores a value into the atomic if the current value is the same
     /// as the expected value.
     ///
@@ -205,3 +350,84 @@ pub trait Atomic: IsAtomic<Atomic = True> + Sized + Send + Sync {
     where
         F: FnMut(Self::NonAtomicType) -> Option<Self::NonAtomicType>;
 }
+
+/// Returns the strongest ordering valid for the load part of an operation
+/// with ordering `order`, as in the implementation of `fetch_update` using
+/// `order` as set ordering.
+#[inline(always)]
+pub(crate) fn load_ordering(order: Ordering) -> Ordering {
+    match order {
+        Ordering::Release => Ordering::Relaxed,
+        Ordering::AcqRel => Ordering::Acquire,
+        order => order,
+    }
+}
+
+/// Applies `f` to the value of `atomic` using
+/// [`fetch_update`](Atomic::fetch_update), returning the previous and the new
+/// value.
+#[inline(always)]
+pub(crate) fn update<A: Atomic>(
+    atomic: &A,
+    set_order: Ordering,
+    fetch_order: Ordering,
+    mut f: impl FnMut(A::NonAtomicType) -> A::NonAtomicType,
+) -> (A::NonAtomicType, A::NonAtomicType)
+where
+    A::NonAtomicType: Copy,
+{
+    let mut new = None;
+    let (Ok(prev) | Err(prev)) = atomic.fetch_update(set_order, fetch_order, |x| {
+        let value = f(x);
+        new = Some(value);
+        Some(value)
+    });
+    // The closure is called at least once, and the last call is the
+    // successful one
+    (prev, new.unwrap())
+}
+
+#[inline(always)]
+pub(crate) fn check_load_ordering(order: Ordering) {
+    match order {
+        Ordering::Release => panic!("there is no such thing as a release load"),
+        Ordering::AcqRel => panic!("there is no such thing as an acquire-release load"),
+        _ => {}
+    }
+}
+
+#[inline(always)]
+pub(crate) fn check_store_ordering(order: Ordering) {
+    match order {
+        Ordering::Acquire => panic!("there is no such thing as an acquire store"),
+        Ordering::AcqRel => panic!("there is no such thing as an acquire-release store"),
+        _ => {}
+    }
+}
+
+#[inline(always)]
+pub(crate) fn check_failure_ordering(order: Ordering) {
+    match order {
+        Ordering::Release => panic!("there is no such thing as a release failure ordering"),
+        Ordering::AcqRel => {
+            panic!("there is no such thing as an acquire-release failure ordering")
+        }
+        _ => {}
+    }
+}
+
+/// Compile-time check that `T` and `U` have the same size and alignment,
+/// used as `let () = SameLayout::<T, U>::CHECK;` before reinterpreting
+/// memory containing values of type `T` as memory containing values of type
+/// `U`.
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub(crate) struct SameLayout<T, U>(core::marker::PhantomData<(T, U)>);
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<T, U> SameLayout<T, U> {
+    pub(crate) const CHECK: () = assert!(
+        core::mem::size_of::<T>() == core::mem::size_of::<U>()
+            && core::mem::align_of::<T>() == core::mem::align_of::<U>(),
+        "the atomic and non-atomic types must have the same size and alignment"
+    );
+}
@@ -0,0 +1,400 @@
+use crate::atomic::{check_failure_ordering, check_load_ordering, check_store_ordering};
+use core::cell::UnsafeCell;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Atomic [`u128`].
+///
+/// On `x86_64` processors supporting the `cmpxchg16b` instruction (detected at
+/// runtime if the `std` feature is enabled and the target feature is not
+/// enabled at compile time) all operations are lock-free. Otherwise, they are
+/// serialized by a global array of spinlocks indexed by the address of the
+/// value.
+///
+/// It has the same memory layout as [`u128`].
+#[derive(Default)]
+#[repr(transparent)]
+pub struct AtomicU128(UnsafeCell<u128>);
+
+/// Atomic [`i128`].
+///
+/// See [`AtomicU128`] for implementation details.
+///
+/// It has the same memory layout as [`i128`].
+#[derive(Default)]
+#[repr(transparent)]
+pub struct AtomicI128(UnsafeCell<i128>);
+
+#[cfg(target_arch = "x86_64")]
+mod cmpxchg16b {
+    use core::arch::asm;
+
+    // cmpxchg16b requires 16-byte aligned operands
+    const _: () = assert!(core::mem::align_of::<u128>() == 16);
+
+    /// Returns whether the processor supports `cmpxchg16b`.
+    #[inline(always)]
+    pub(super) fn available() -> bool {
+        #[cfg(target_feature = "cmpxchg16b")]
+        {
+            true
+        }
+        #[cfg(all(not(target_feature = "cmpxchg16b"), feature = "std"))]
+        {
+            std::is_x86_feature_detected!("cmpxchg16b")
+        }
+        #[cfg(all(not(target_feature = "cmpxchg16b"), not(feature = "std")))]
+        {
+            false
+        }
+    }
+
+    /// Stores `new` in `dst` if its value is `old`, returning the previous
+    /// value and whether the store happened. The operation is sequentially
+    /// consistent.
+    ///
+    /// # Safety
+    ///
+    /// `dst` must be valid for reads and writes and 16-byte aligned, and the
+    /// processor must support `cmpxchg16b`.
+    #[inline(always)]
+    pub(super) unsafe fn compare_exchange(dst: *mut u128, old: u128, new: u128) -> (u128, bool) {
+        let (prev_lo, prev_hi): (u64, u64);
+        let success: u8;
+        unsafe {
+            asm!(
+                // rbx is reserved by LLVM, so we swap it with a scratch register
+                "xchg {new_lo}, rbx",
+                "lock cmpxchg16b xmmword ptr [{dst}]",
+                "sete cl",
+                "mov rbx, {new_lo}",
+                dst = in(reg) dst,
+                new_lo = inout(reg) new as u64 => _,
+                in("rcx") (new >> 64) as u64,
+                inout("rax") old as u64 => prev_lo,
+                inout("rdx") (old >> 64) as u64 => prev_hi,
+                lateout("cl") success,
+                options(nostack),
+            );
+        }
+        (prev_lo as u128 | (prev_hi as u128) << 64, success != 0)
+    }
+}
+
+/// Number of spinlocks used when `cmpxchg16b` is not available.
+const STRIPES: usize = 64;
+
+/// A spinlock padded to a cache line to avoid false sharing.
+#[repr(align(64))]
+struct Stripe(AtomicBool);
+
+static STRIPED_LOCKS: [Stripe; STRIPES] = [const { Stripe(AtomicBool::new(false)) }; STRIPES];
+
+/// Releases the spinlock when dropped.
+struct StripeGuard(&'static AtomicBool);
+
+impl Drop for StripeGuard {
+    #[inline(always)]
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Acquires the spinlock associated with the address `dst`.
+#[inline(always)]
+fn lock(dst: *mut u128) -> StripeGuard {
+    let lock = &STRIPED_LOCKS[(dst as usize >> 4) % STRIPES].0;
+    while lock
+        .compare_exchange_weak(false, true, Ordering::SeqCst, Ordering::Relaxed)
+        .is_err()
+    {
+        while lock.load(Ordering::Relaxed) {
+            core::hint::spin_loop();
+        }
+    }
+    StripeGuard(lock)
+}
+
+/// Atomically loads the value at `dst`.
+///
+/// # Safety
+///
+/// `dst` must be valid for reads and writes, and all concurrent accesses
+/// must happen through the functions of this module.
+#[inline(always)]
+unsafe fn load(dst: *mut u128) -> u128 {
+    #[cfg(target_arch = "x86_64")]
+    if cmpxchg16b::available() {
+        // If the value is zero, we overwrite it with zero
+        return unsafe { cmpxchg16b::compare_exchange(dst, 0, 0).0 };
+    }
+    let _guard = lock(dst);
+    unsafe { dst.read() }
+}
+
+/// Atomically stores `new` at `dst` if the current value is `old`.
+///
+/// # Safety
+///
+/// See [`load`].
+#[inline(always)]
+unsafe fn compare_exchange(dst: *mut u128, old: u128, new: u128) -> Result<u128, u128> {
+    #[cfg(target_arch = "x86_64")]
+    if cmpxchg16b::available() {
+        return match unsafe { cmpxchg16b::compare_exchange(dst, old, new) } {
+            (prev, true) => Ok(prev),
+            (prev, false) => Err(prev),
+        };
+    }
+    let _guard = lock(dst);
+    let prev = unsafe { dst.read() };
+    if prev == old {
+        unsafe { dst.write(new) };
+        Ok(prev)
+    } else {
+        Err(prev)
+    }
+}
+
+/// Atomically replaces the value at `dst` with the result of `f`, returning
+/// the previous value. `f` might be called multiple times, and must not
+/// access other values of this module.
+///
+/// # Safety
+///
+/// See [`load`].
+#[inline(always)]
+unsafe fn read_modify_write(dst: *mut u128, mut f: impl FnMut(u128) -> u128) -> u128 {
+    #[cfg(target_arch = "x86_64")]
+    if cmpxchg16b::available() {
+        let mut prev = unsafe { cmpxchg16b::compare_exchange(dst, 0, 0).0 };
+        loop {
+            match unsafe { cmpxchg16b::compare_exchange(dst, prev, f(prev)) } {
+                (prev, true) => return prev,
+                (current, false) => prev = current,
+            }
+        }
+    }
+    let _guard = lock(dst);
+    let prev = unsafe { dst.read() };
+    unsafe { dst.write(f(prev)) };
+    prev
+}
+
+macro_rules! impl_atomic_128 {
+    ($aty:ident, $ty:ty) => {
+        // SAFETY: all accesses through a shared reference are atomic
+        unsafe impl Sync for $aty {}
+
+        impl core::fmt::Debug for $aty {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+            }
+        }
+
+        impl From<$ty> for $aty {
+            #[inline(always)]
+            fn from(value: $ty) -> Self {
+                Self::new(value)
+            }
+        }
+
+        /// The methods mirror those of the atomic types of the standard
+        /// library. Since all operations are sequentially consistent, the
+        /// orderings are only checked for validity.
+        impl $aty {
+            /// Creates a new atomic integer.
+            #[inline(always)]
+            pub const fn new(value: $ty) -> Self {
+                Self(UnsafeCell::new(value))
+            }
+
+            #[inline(always)]
+            fn as_u128_ptr(&self) -> *mut u128 {
+                self.0.get() as *mut u128
+            }
+
+            /// Returns a mutable reference to the underlying integer.
+            #[inline(always)]
+            pub fn get_mut(&mut self) -> &mut $ty {
+                self.0.get_mut()
+            }
+
+            /// Consumes the atomic and returns the contained value.
+            #[inline(always)]
+            pub fn into_inner(self) -> $ty {
+                self.0.into_inner()
+            }
+
+            /// Loads a value from the atomic integer.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `order` is [`Release`](Ordering::Release) or
+            /// [`AcqRel`](Ordering::AcqRel).
+            #[inline(always)]
+            pub fn load(&self, order: Ordering) -> $ty {
+                check_load_ordering(order);
+                unsafe { load(self.as_u128_ptr()) as $ty }
+            }
+
+            /// Stores a value into the atomic integer.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `order` is [`Acquire`](Ordering::Acquire) or
+            /// [`AcqRel`](Ordering::AcqRel).
+            #[inline(always)]
+            pub fn store(&self, value: $ty, order: Ordering) {
+                check_store_ordering(order);
+                unsafe { read_modify_write(self.as_u128_ptr(), |_| value as u128) };
+            }
+
+            /// Stores a value into the atomic integer, returning the previous
+            /// value.
+            #[inline(always)]
+            pub fn swap(&self, value: $ty, _order: Ordering) -> $ty {
+                unsafe { read_modify_write(self.as_u128_ptr(), |_| value as u128) as $ty }
+            }
+
+            /// Stores `new` into the atomic integer if the current value is
+            /// `current`.
+            ///
+            /// The return value is a result indicating whether the new value
+            /// was written and containing the previous value.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `failure` is [`Release`](Ordering::Release) or
+            /// [`AcqRel`](Ordering::AcqRel).
+            #[inline(always)]
+            pub fn compare_exchange(
+                &self,
+                current: $ty,
+                new: $ty,
+                _success: Ordering,
+                failure: Ordering,
+            ) -> Result<$ty, $ty> {
+                check_failure_ordering(failure);
+                unsafe { compare_exchange(self.as_u128_ptr(), current as u128, new as u128) }
+                    .map(|x| x as $ty)
+                    .map_err(|x| x as $ty)
+            }
+
+            /// Same as [`compare_exchange`](Self::compare_exchange), as
+            /// there are no spurious failures.
+            #[inline(always)]
+            pub fn compare_exchange_weak(
+                &self,
+                current: $ty,
+                new: $ty,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<$ty, $ty> {
+                self.compare_exchange(current, new, success, failure)
+            }
+
+            /// Fetches the value, and applies a function to it that returns an
+            /// optional new value. Returns `Ok(previous_value)` if the function
+            /// returned `Some(_)`, else `Err(previous_value)`.
+            ///
+            /// The function may be called multiple times if the value has been
+            /// changed from other threads in the meantime.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `fetch_order` is [`Release`](Ordering::Release) or
+            /// [`AcqRel`](Ordering::AcqRel).
+            #[inline]
+            pub fn fetch_update<F>(
+                &self,
+                set_order: Ordering,
+                fetch_order: Ordering,
+                mut f: F,
+            ) -> Result<$ty, $ty>
+            where
+                F: FnMut($ty) -> Option<$ty>,
+            {
+                let mut prev = self.load(fetch_order);
+                // The function is never called while holding a lock
+                while let Some(next) = f(prev) {
+                    match self.compare_exchange(prev, next, set_order, fetch_order) {
+                        Ok(x) => return Ok(x),
+                        Err(current) => prev = current,
+                    }
+                }
+                Err(prev)
+            }
+
+            /// Adds to the current value, wrapping around on overflow, and
+            /// returns the previous value.
+            #[inline(always)]
+            pub fn fetch_add(&self, value: $ty, _order: Ordering) -> $ty {
+                unsafe {
+                    read_modify_write(self.as_u128_ptr(), |x| {
+                        (x as $ty).wrapping_add(value) as u128
+                    }) as $ty
+                }
+            }
+
+            /// Subtracts from the current value, wrapping around on overflow,
+            /// and returns the previous value.
+            #[inline(always)]
+            pub fn fetch_sub(&self, value: $ty, _order: Ordering) -> $ty {
+                unsafe {
+                    read_modify_write(self.as_u128_ptr(), |x| {
+                        (x as $ty).wrapping_sub(value) as u128
+                    }) as $ty
+                }
+            }
+
+            /// Stores the maximum of the current value and `value`, returning
+            /// the previous value.
+            #[inline(always)]
+            pub fn fetch_max(&self, value: $ty, _order: Ordering) -> $ty {
+                unsafe {
+                    read_modify_write(self.as_u128_ptr(), |x| (x as $ty).max(value) as u128) as $ty
+                }
+            }
+
+            /// Stores the minimum of the current value and `value`, returning
+            /// the previous value.
+            #[inline(always)]
+            pub fn fetch_min(&self, value: $ty, _order: Ordering) -> $ty {
+                unsafe {
+                    read_modify_write(self.as_u128_ptr(), |x| (x as $ty).min(value) as u128) as $ty
+                }
+            }
+
+            /// Bitwise “and” with the current value, returning the previous
+            /// value.
+            #[inline(always)]
+            pub fn fetch_and(&self, value: $ty, _order: Ordering) -> $ty {
+                unsafe { read_modify_write(self.as_u128_ptr(), |x| x & value as u128) as $ty }
+            }
+
+            /// Bitwise “nand” with the current value, returning the previous
+            /// value.
+            #[inline(always)]
+            pub fn fetch_nand(&self, value: $ty, _order: Ordering) -> $ty {
+                unsafe { read_modify_write(self.as_u128_ptr(), |x| !(x & value as u128)) as $ty }
+            }
+
+            /// Bitwise “or” with the current value, returning the previous
+            /// value.
+            #[inline(always)]
+            pub fn fetch_or(&self, value: $ty, _order: Ordering) -> $ty {
+                unsafe { read_modify_write(self.as_u128_ptr(), |x| x | value as u128) as $ty }
+            }
+
+            /// Bitwise “xor” with the current value, returning the previous
+            /// value.
+            #[inline(always)]
+            pub fn fetch_xor(&self, value: $ty, _order: Ordering) -> $ty {
+                unsafe { read_modify_write(self.as_u128_ptr(), |x| x ^ value as u128) as $ty }
+            }
+        }
+    };
+}
+
+impl_atomic_128!(AtomicU128, u128);
+impl_atomic_128!(AtomicI128, i128);
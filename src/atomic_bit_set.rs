@@ -0,0 +1,250 @@
+use crate::atomic::load_ordering;
+use crate::{AsBytes, Atomic, AtomicUnsignedInt, Integer, Number, SelectInWord, UnsignedInt};
+use core::sync::atomic::Ordering;
+
+/// A concurrent bit set stored in a slice of atomic unsigned integers, such as
+/// `[AtomicU64]` or `[AtomicUsize]`.
+///
+/// Bit `i` is bit `i % BITS` of the word of index `i / BITS`, where `BITS` is
+/// the number of bits of a word. All methods modifying single bits are
+/// lock-free, as they use [`fetch_or`](crate::AtomicInteger::fetch_or),
+/// [`fetch_and`](crate::AtomicInteger::fetch_and) and
+/// [`fetch_xor`](crate::AtomicInteger::fetch_xor) on the word containing the
+/// bit. Methods scanning the whole set load each word atomically, but they do
+/// not take a snapshot of the set: concurrent modifications might or might
+/// not be observed.
+///
+/// Since the trait is implemented for slices, it can be used on vectors and
+/// boxed slices, and a set can be processed in parallel by splitting it into
+/// subslices (e.g., using [`chunks`](slice::chunks)), adding to the indices
+/// returned by each subslice the number of bits preceding it.
+///
+/// The methods have a `_bit` suffix when the name without suffix would
+/// clash with other methods of slices.
+///
+/// # Examples
+///
+/// ```rust
+/// use common_traits::AtomicBitSet;
+/// use core::sync::atomic::{AtomicU64, Ordering};
+///
+/// let visited: Vec<AtomicU64> = (0..2).map(|_| AtomicU64::new(0)).collect();
+/// assert!(!visited.test_and_set(3, Ordering::Relaxed));
+/// assert!(visited.test_and_set(3, Ordering::Relaxed));
+/// assert!(!visited.set_bit(100, Ordering::Relaxed));
+/// assert_eq!(visited.count_ones(Ordering::Relaxed), 2);
+/// assert_eq!(visited.select(1, Ordering::Relaxed), Some(100));
+/// assert_eq!(visited.iter_ones(Ordering::Relaxed).collect::<Vec<_>>(), vec![3, 100]);
+/// ```
+pub trait AtomicBitSet {
+    /// The type of the words of the bit set.
+    type Word: UnsignedInt;
+
+    /// Returns the number of bits in the set.
+    fn num_bits(&self) -> usize;
+
+    /// Returns the value of the bit of given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not smaller than [`num_bits`](Self::num_bits),
+    /// or if `order` is [`Release`](`core::sync::atomic::Ordering::Release`)
+    /// or [`AcqRel`](`core::sync::atomic::Ordering::AcqRel`).
+    fn get_bit(&self, index: usize, order: Ordering) -> bool;
+
+    /// Sets the bit of given index, returning its previous value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not smaller than [`num_bits`](Self::num_bits).
+    fn set_bit(&self, index: usize, order: Ordering) -> bool;
+
+    /// Clears the bit of given index, returning its previous value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not smaller than [`num_bits`](Self::num_bits).
+    fn clear_bit(&self, index: usize, order: Ordering) -> bool;
+
+    /// Flips the bit of given index, returning its previous value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not smaller than [`num_bits`](Self::num_bits).
+    fn toggle_bit(&self, index: usize, order: Ordering) -> bool;
+
+    /// Sets the bit of given index, returning its previous value.
+    ///
+    /// Differently from [`set_bit`](Self::set_bit), the bit is loaded first,
+    /// and the word is modified only if the bit is not set, which is faster
+    /// when most bits are already set, as when marking visited nodes. If the
+    /// bit is found set, the operation is a load with ordering `order`
+    /// weakened to be valid for a load (i.e.,
+    /// [`Release`](`core::sync::atomic::Ordering::Release`) becomes
+    /// [`Relaxed`](`core::sync::atomic::Ordering::Relaxed`) and
+    /// [`AcqRel`](`core::sync::atomic::Ordering::AcqRel`) becomes
+    /// [`Acquire`](`core::sync::atomic::Ordering::Acquire`)).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not smaller than [`num_bits`](Self::num_bits).
+    fn test_and_set(&self, index: usize, order: Ordering) -> bool;
+
+    /// Returns the number of bits set.
+    fn count_ones(&self, order: Ordering) -> usize;
+
+    /// Returns the index of the set bit of given rank (i.e., of the
+    /// `rank + 1`-th set bit), or `None` if there are not enough set bits.
+    ///
+    /// The search uses [`SelectInWord`] on the word containing the bit.
+    fn select(&self, rank: usize, order: Ordering) -> Option<usize>;
+
+    /// Returns an iterator on the indices of the set bits, in increasing
+    /// order. Each word is loaded when the iterator reaches it.
+    fn iter_ones(&self, order: Ordering) -> IterOnes<'_, Self>;
+
+    /// Swaps atomically each word of the set with the word of `words` with
+    /// the same index, e.g., to clear a set while retrieving its content.
+    ///
+    /// Each word is swapped atomically, but the whole operation is not atomic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `words` does not contain as many words as the set.
+    fn swap_words(&self, words: &mut [Self::Word], order: Ordering);
+}
+
+impl<A: AtomicUnsignedInt> AtomicBitSet for [A]
+where
+    A::NonAtomicType: UnsignedInt + SelectInWord + AsBytes,
+{
+    type Word = A::NonAtomicType;
+
+    #[inline(always)]
+    fn num_bits(&self) -> usize {
+        self.len() * A::NonAtomicType::BITS
+    }
+
+    #[inline(always)]
+    fn get_bit(&self, index: usize, order: Ordering) -> bool {
+        let (word, mask) = word_and_mask::<A>(index);
+        self[word].load(order) & mask != A::NonAtomicType::ZERO
+    }
+
+    #[inline(always)]
+    fn set_bit(&self, index: usize, order: Ordering) -> bool {
+        let (word, mask) = word_and_mask::<A>(index);
+        self[word].fetch_or(mask, order) & mask != A::NonAtomicType::ZERO
+    }
+
+    #[inline(always)]
+    fn clear_bit(&self, index: usize, order: Ordering) -> bool {
+        let (word, mask) = word_and_mask::<A>(index);
+        self[word].fetch_and(!mask, order) & mask != A::NonAtomicType::ZERO
+    }
+
+    #[inline(always)]
+    fn toggle_bit(&self, index: usize, order: Ordering) -> bool {
+        let (word, mask) = word_and_mask::<A>(index);
+        self[word].fetch_xor(mask, order) & mask != A::NonAtomicType::ZERO
+    }
+
+    #[inline(always)]
+    fn test_and_set(&self, index: usize, order: Ordering) -> bool {
+        let (word, mask) = word_and_mask::<A>(index);
+        let word = &self[word];
+        if word.load(load_ordering(order)) & mask != A::NonAtomicType::ZERO {
+            return true;
+        }
+        word.fetch_or(mask, order) & mask != A::NonAtomicType::ZERO
+    }
+
+    fn count_ones(&self, order: Ordering) -> usize {
+        self.iter()
+            .map(|word| word.load(order).count_ones() as usize)
+            .sum()
+    }
+
+    fn select(&self, mut rank: usize, order: Ordering) -> Option<usize> {
+        for (index, word) in self.iter().enumerate() {
+            let word = word.load(order);
+            let ones = word.count_ones() as usize;
+            if rank < ones {
+                return Some(index * A::NonAtomicType::BITS + word.select_in_word(rank));
+            }
+            rank -= ones;
+        }
+        None
+    }
+
+    #[inline(always)]
+    fn iter_ones(&self, order: Ordering) -> IterOnes<'_, Self> {
+        IterOnes {
+            words: self,
+            next_word: 0,
+            word: A::NonAtomicType::ZERO,
+            base: 0,
+            order,
+        }
+    }
+
+    fn swap_words(&self, words: &mut [Self::Word], order: Ordering) {
+        assert_eq!(
+            self.len(),
+            words.len(),
+            "the bit set and the words have different lengths"
+        );
+        for (atomic, word) in self.iter().zip(words) {
+            *word = atomic.swap(*word, order);
+        }
+    }
+}
+
+/// Returns the index of the word containing the bit of given index and the
+/// mask selecting the bit in the word.
+#[inline(always)]
+fn word_and_mask<A: Atomic>(index: usize) -> (usize, A::NonAtomicType)
+where
+    A::NonAtomicType: UnsignedInt + AsBytes,
+{
+    let bits = A::NonAtomicType::BITS;
+    (index / bits, A::NonAtomicType::ONE << (index % bits))
+}
+
+/// The iterator returned by [`AtomicBitSet::iter_ones`].
+#[derive(Debug)]
+pub struct IterOnes<'a, S: AtomicBitSet + ?Sized> {
+    words: &'a S,
+    /// The index of the next word to load.
+    next_word: usize,
+    /// The bits of the current word that have not been returned yet.
+    word: S::Word,
+    /// The index of the first bit of the current word.
+    base: usize,
+    order: Ordering,
+}
+
+impl<A: AtomicUnsignedInt> Iterator for IterOnes<'_, [A]>
+where
+    A::NonAtomicType: UnsignedInt + SelectInWord + AsBytes,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.word == A::NonAtomicType::ZERO {
+            let word = self.words.get(self.next_word)?;
+            self.word = word.load(self.order);
+            self.base = self.next_word * A::NonAtomicType::BITS;
+            self.next_word += 1;
+        }
+        let bit = self.word.trailing_zeros() as usize;
+        // Clear the lowest set bit
+        self.word &= self.word - A::NonAtomicType::ONE;
+        Some(self.base + bit)
+    }
+}
+
+impl<A: AtomicUnsignedInt> core::iter::FusedIterator for IterOnes<'_, [A]> where
+    A::NonAtomicType: UnsignedInt + SelectInWord + AsBytes
+{
+}
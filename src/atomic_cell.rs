@@ -0,0 +1,368 @@
+use crate::sync::{AtomicU8, AtomicU16, AtomicU32, AtomicU64};
+use crate::{AsBytes, Atomic, AtomicU128, FromBytes, IntoAtomic, IsAtomic, SameAs, ToBytes, True};
+use core::sync::atomic::Ordering;
+
+mod private {
+    use crate::Atomic;
+
+    /// Sealed trait associating byte arrays with the atomic unsigned integer
+    /// of the same size.
+    pub trait AtomicBytes: Copy {
+        type Atomic: Atomic;
+        fn into_int(self) -> <Self::Atomic as Atomic>::NonAtomicType;
+        fn from_int(int: <Self::Atomic as Atomic>::NonAtomicType) -> Self;
+    }
+}
+
+use private::AtomicBytes;
+
+macro_rules! impl_atomic_bytes {
+    ($($ty:ty, $aty:ty;)*) => {$(
+impl AtomicBytes for [u8; core::mem::size_of::<$ty>()] {
+    type Atomic = $aty;
+
+    #[inline(always)]
+    fn into_int(self) -> $ty {
+        <$ty>::from_ne_bytes(self)
+    }
+
+    #[inline(always)]
+    fn from_int(int: $ty) -> Self {
+        int.to_ne_bytes()
+    }
+}
+    )*};
+}
+
+impl_atomic_bytes!(
+    u8, AtomicU8;
+    u16, AtomicU16;
+    u32, AtomicU32;
+    u64, AtomicU64;
+    u128, AtomicU128;
+);
+
+/// Atomic version of any type of 1, 2, 4, 8 or 16 bytes that can be converted
+/// to and from bytes, based on the atomic unsigned integer of the same size
+/// (e.g., [`AtomicU32`] for a type of 4 bytes).
+///
+/// Values are converted to and from integers using [`ToBytes::to_ne_bytes`]
+/// and [`FromBytes::from_ne_bytes`], so two values are considered equal by
+/// [`compare_exchange`](Atomic::compare_exchange) if their byte
+/// representations are equal.
+///
+/// To use a type `T` with [`AtomicCell`], you must implement
+/// [`IntoAtomic`] using the [`impl_into_atomic_cell!`](crate::impl_into_atomic_cell)
+/// macro, and then implement [`SameAs`] manually, guaranteeing that
+/// the memory representation of `T` is given by [`ToBytes::to_ne_bytes`],
+/// which implies that `T` has no padding. The size and alignment of `T` and
+/// of `AtomicCell<T>` are checked at compile time by
+/// [`new`](Atomic::new) and by the methods converting between references to
+/// `T` and to `AtomicCell<T>`, so that a type with a smaller alignment than
+/// the underlying atomic integer cannot be used.
+///
+/// # Examples
+///
+/// ```rust
+/// use common_traits::*;
+/// use core::sync::atomic::Ordering;
+///
+/// #[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// #[repr(C, align(4))]
+/// pub struct Pair(u16, u16);
+///
+/// impl AsBytes for Pair {
+///     const BYTES: usize = 4;
+///     const BITS: usize = 32;
+///     type Bytes = [u8; 4];
+/// }
+///
+/// impl ToBytes for Pair {
+///     fn to_be_bytes(self) -> [u8; 4] {
+///         let ([a, b], [c, d]) = (self.0.to_be_bytes(), self.1.to_be_bytes());
+///         [a, b, c, d]
+///     }
+///     fn to_le_bytes(self) -> [u8; 4] {
+///         let ([a, b], [c, d]) = (self.0.to_le_bytes(), self.1.to_le_bytes());
+///         [a, b, c, d]
+///     }
+///     fn to_ne_bytes(self) -> [u8; 4] {
+///         let ([a, b], [c, d]) = (self.0.to_ne_bytes(), self.1.to_ne_bytes());
+///         [a, b, c, d]
+///     }
+/// }
+///
+/// impl FromBytes for Pair {
+///     fn from_be_bytes([a, b, c, d]: [u8; 4]) -> Self {
+///         Pair(u16::from_be_bytes([a, b]), u16::from_be_bytes([c, d]))
+///     }
+///     fn from_le_bytes([a, b, c, d]: [u8; 4]) -> Self {
+///         Pair(u16::from_le_bytes([a, b]), u16::from_le_bytes([c, d]))
+///     }
+///     fn from_ne_bytes([a, b, c, d]: [u8; 4]) -> Self {
+///         Pair(u16::from_ne_bytes([a, b]), u16::from_ne_bytes([c, d]))
+///     }
+/// }
+///
+/// impl_into_atomic_cell!(Pair);
+/// // Pair is made of two u16 in order, without padding
+/// unsafe impl SameAs<AtomicCell<Pair>> for Pair {}
+///
+/// let pair = Pair(1, 2).to_atomic();
+/// assert_eq!(pair.swap(Pair(3, 4), Ordering::Relaxed), Pair(1, 2));
+/// assert_eq!(
+///     pair.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |p| Some(Pair(p.1, p.0))),
+///     Ok(Pair(3, 4))
+/// );
+/// assert_eq!(pair.load(Ordering::Relaxed), Pair(4, 3));
+/// ```
+#[repr(transparent)]
+pub struct AtomicCell<T: AsBytes>(<T::Bytes as AtomicBytes>::Atomic)
+where
+    T::Bytes: AtomicBytes;
+
+impl<T: AsBytes> IsAtomic for AtomicCell<T>
+where
+    T::Bytes: AtomicBytes,
+{
+    type Atomic = True;
+}
+
+impl<T> AtomicCell<T>
+where
+    T: AsBytes + FromBytes + ToBytes,
+    T::Bytes: AtomicBytes,
+{
+    /// Checks at compile time that `T` and `AtomicCell<T>` have the same size
+    /// and alignment.
+    const SAME_LAYOUT: () = assert!(
+        core::mem::size_of::<T>() == core::mem::size_of::<Self>()
+            && core::mem::align_of::<T>() == core::mem::align_of::<Self>(),
+        "the type must have the size and alignment of the atomic integer of the same size"
+    );
+
+    #[inline(always)]
+    fn into_int(value: T) -> <<T::Bytes as AtomicBytes>::Atomic as Atomic>::NonAtomicType {
+        value.to_ne_bytes().into_int()
+    }
+
+    #[inline(always)]
+    fn from_int(int: <<T::Bytes as AtomicBytes>::Atomic as Atomic>::NonAtomicType) -> T {
+        T::from_ne_bytes(T::Bytes::from_int(int))
+    }
+}
+
+impl<T> Default for AtomicCell<T>
+where
+    T: IntoAtomic<AtomicType = Self> + SameAs<Self> + AsBytes + FromBytes + ToBytes,
+    T::Bytes: AtomicBytes,
+{
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> core::fmt::Debug for AtomicCell<T>
+where
+    T: IntoAtomic<AtomicType = Self> + SameAs<Self> + AsBytes + FromBytes + ToBytes,
+    T: core::fmt::Debug,
+    T::Bytes: AtomicBytes,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
+
+impl<T> Atomic for AtomicCell<T>
+where
+    T: IntoAtomic<AtomicType = Self> + SameAs<Self> + AsBytes + FromBytes + ToBytes,
+    T::Bytes: AtomicBytes,
+{
+    type NonAtomicType = T;
+
+    #[inline(always)]
+    fn new(value: Self::NonAtomicType) -> Self {
+        let () = Self::SAME_LAYOUT;
+        Self(<T::Bytes as AtomicBytes>::Atomic::new(Self::into_int(
+            value,
+        )))
+    }
+
+    #[inline(always)]
+    fn load(&self, order: Ordering) -> Self::NonAtomicType {
+        Self::from_int(self.0.load(order))
+    }
+
+    #[inline(always)]
+    fn store(&self, value: Self::NonAtomicType, order: Ordering) {
+        self.0.store(Self::into_int(value), order)
+    }
+
+    #[inline(always)]
+    fn get_mut(&mut self) -> &mut Self::NonAtomicType {
+        let () = Self::SAME_LAYOUT;
+        unsafe { &mut *(self as *mut Self as *mut Self::NonAtomicType) }
+    }
+
+    #[inline(always)]
+    fn into_inner(self) -> Self::NonAtomicType {
+        Self::from_int(self.0.into_inner())
+    }
+
+    #[inline(always)]
+    fn into_non_atomic_array<const N: usize>(data: [Self; N]) -> [Self::NonAtomicType; N] {
+        data.map(Self::into_inner)
+    }
+
+    #[inline(always)]
+    fn from_non_atomic_array<const N: usize>(data: [Self::NonAtomicType; N]) -> [Self; N] {
+        data.map(Self::new)
+    }
+
+    #[inline(always)]
+    fn get_mut_slice(this: &mut [Self]) -> &mut [Self::NonAtomicType] {
+        let () = Self::SAME_LAYOUT;
+        unsafe {
+            core::slice::from_raw_parts_mut(
+                this.as_mut_ptr() as *mut Self::NonAtomicType,
+                this.len(),
+            )
+        }
+    }
+
+    #[inline(always)]
+    fn from_mut_slice(this: &mut [Self::NonAtomicType]) -> &mut [Self] {
+        let () = Self::SAME_LAYOUT;
+        unsafe { core::slice::from_raw_parts_mut(this.as_mut_ptr() as *mut Self, this.len()) }
+    }
+
+    #[inline(always)]
+    fn get_mut_array<const N: usize>(this: &mut [Self; N]) -> &mut [Self::NonAtomicType; N] {
+        let () = Self::SAME_LAYOUT;
+        unsafe { &mut *(this as *mut [Self; N] as *mut [Self::NonAtomicType; N]) }
+    }
+
+    #[inline(always)]
+    fn from_mut_array<const N: usize>(this: &mut [Self::NonAtomicType; N]) -> &mut [Self; N] {
+        let () = Self::SAME_LAYOUT;
+        unsafe { &mut *(this as *mut [Self::NonAtomicType; N] as *mut [Self; N]) }
+    }
+
+    #[inline(always)]
+    fn compare_exchange(
+        &self,
+        current: Self::NonAtomicType,
+        new: Self::NonAtomicType,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self::NonAtomicType, Self::NonAtomicType> {
+        self.0
+            .compare_exchange(
+                Self::into_int(current),
+                Self::into_int(new),
+                success,
+                failure,
+            )
+            .map(Self::from_int)
+            .map_err(Self::from_int)
+    }
+
+    #[inline(always)]
+    fn compare_exchange_weak(
+        &self,
+        current: Self::NonAtomicType,
+        new: Self::NonAtomicType,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self::NonAtomicType, Self::NonAtomicType> {
+        self.0
+            .compare_exchange_weak(
+                Self::into_int(current),
+                Self::into_int(new),
+                success,
+                failure,
+            )
+            .map(Self::from_int)
+            .map_err(Self::from_int)
+    }
+
+    #[inline(always)]
+    fn swap(&self, new: Self::NonAtomicType, order: Ordering) -> Self::NonAtomicType {
+        Self::from_int(self.0.swap(Self::into_int(new), order))
+    }
+
+    #[inline(always)]
+    fn fetch_update<F>(
+        &self,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        mut f: F,
+    ) -> Result<Self::NonAtomicType, Self::NonAtomicType>
+    where
+        F: FnMut(Self::NonAtomicType) -> Option<Self::NonAtomicType>,
+    {
+        self.0
+            .fetch_update(set_order, fetch_order, |x| {
+                f(Self::from_int(x)).map(Self::into_int)
+            })
+            .map(Self::from_int)
+            .map_err(Self::from_int)
+    }
+}
+
+/// Implements [`IsAtomic`](crate::IsAtomic) and
+/// [`IntoAtomic`](crate::IntoAtomic) for the given types, using
+/// [`AtomicCell`](crate::AtomicCell) as atomic type.
+///
+/// The types must implement [`AsBytes`](crate::AsBytes),
+/// [`FromBytes`](crate::FromBytes), [`ToBytes`](crate::ToBytes) and
+/// [`SameAs`](crate::SameAs). See [`AtomicCell`](crate::AtomicCell) for an
+/// example.
+#[macro_export]
+macro_rules! impl_into_atomic_cell {
+    ($($ty:ty),* $(,)?) => {$(
+        impl $crate::IsAtomic for $ty {
+            type Atomic = $crate::False;
+        }
+
+        impl $crate::IntoAtomic for $ty {
+            type AtomicType = $crate::AtomicCell<$ty>;
+
+            #[inline(always)]
+            fn to_atomic(self) -> Self::AtomicType {
+                <Self::AtomicType as $crate::Atomic>::new(self)
+            }
+
+            #[inline(always)]
+            fn into_atomic_array<const N: usize>(data: [Self; N]) -> [Self::AtomicType; N] {
+                <Self::AtomicType as $crate::Atomic>::from_non_atomic_array(data)
+            }
+
+            #[inline(always)]
+            fn from_atomic_array<const N: usize>(data: [Self::AtomicType; N]) -> [Self; N] {
+                <Self::AtomicType as $crate::Atomic>::into_non_atomic_array(data)
+            }
+
+            #[inline(always)]
+            fn get_mut_slice(this: &mut [Self::AtomicType]) -> &mut [Self] {
+                <Self::AtomicType as $crate::Atomic>::get_mut_slice(this)
+            }
+
+            #[inline(always)]
+            fn from_mut_slice(this: &mut [Self]) -> &mut [Self::AtomicType] {
+                <Self::AtomicType as $crate::Atomic>::from_mut_slice(this)
+            }
+
+            #[inline(always)]
+            fn get_mut_array<const N: usize>(this: &mut [Self::AtomicType; N]) -> &mut [Self; N] {
+                <Self::AtomicType as $crate::Atomic>::get_mut_array(this)
+            }
+
+            #[inline(always)]
+            fn from_mut_array<const N: usize>(this: &mut [Self; N]) -> &mut [Self::AtomicType; N] {
+                <Self::AtomicType as $crate::Atomic>::from_mut_array(this)
+            }
+        }
+    )*};
+}
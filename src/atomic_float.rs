@@ -1,11 +1,17 @@
+use crate::atomic::{load_ordering, update};
+#[cfg(loom)]
+use crate::sync::check_layout;
+use crate::sync::{AtomicU32, AtomicU64};
 use crate::{
     Atomic, AtomicFiniteRangeNumber, AtomicNumber, False, FiniteRangeNumber, Float, IsFloat,
     IsInteger, IsNonZero, IsSigned, Number, True,
 };
-use core::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use core::sync::atomic::Ordering;
 
 #[cfg(feature = "half")]
-use core::sync::atomic::AtomicU16;
+use crate::sync::AtomicU16;
+#[cfg(not(loom))]
+use crate::sync::{AtomicI32, AtomicI64};
 
 /// Atomic [`f64`] based on [`AtomicU64`].
 #[derive(Debug)]
@@ -17,8 +23,56 @@ pub struct AtomicF64(AtomicU64);
 #[repr(transparent)]
 pub struct AtomicF32(AtomicU32);
 
+/// Implements [`AtomicFloat::fetch_max_total`] and
+/// [`AtomicFloat::fetch_min_total`] using native operations on the bit
+/// patterns stored in the field `0` of type `$inner`, viewed also as `$signed`.
+///
+/// Atomics of the loom backend cannot be viewed as a different type, so under
+/// `cfg(loom)` the macro expands to nothing and the default
+/// implementations are used.
+macro_rules! impl_total_min_max {
+    ($inner:ty, $signed:ty) => {
+        #[cfg(not(loom))]
+        #[inline(always)]
+        fn fetch_max_total(
+            &self,
+            value: Self::NonAtomicType,
+            order: Ordering,
+        ) -> Self::NonAtomicType {
+            let bits = value.to_bits();
+            Self::NonAtomicType::from_bits(if (bits as <$signed as Atomic>::NonAtomicType) >= 0 {
+                // Same size and alignment
+                let signed = unsafe { &*(&self.0 as *const $inner as *const $signed) };
+                signed.fetch_max(bits as _, order) as _
+            } else {
+                self.0.fetch_min(bits, order)
+            })
+        }
+
+        #[cfg(not(loom))]
+        #[inline(always)]
+        fn fetch_min_total(
+            &self,
+            value: Self::NonAtomicType,
+            order: Ordering,
+        ) -> Self::NonAtomicType {
+            let bits = value.to_bits();
+            Self::NonAtomicType::from_bits(if (bits as <$signed as Atomic>::NonAtomicType) >= 0 {
+                // Same size and alignment
+                let signed = unsafe { &*(&self.0 as *const $inner as *const $signed) };
+                signed.fetch_min(bits as _, order) as _
+            } else {
+                self.0.fetch_max(bits, order)
+            })
+        }
+    };
+}
+
+#[cfg(feature = "half")]
+pub(crate) use impl_total_min_max;
+
 macro_rules! impl_atomic_float {
-    ($ty:ty, $atomic:ty, $inner:ty) => {
+    ($ty:ty, $atomic:ty, $inner:ty, $signed:ty) => {
         impl core::default::Default for $atomic {
             fn default() -> Self {
                 Self::new(<Self as Atomic>::NonAtomicType::ZERO)
@@ -41,6 +95,8 @@ macro_rules! impl_atomic_float {
             }
 
             fn get_mut(&mut self) -> &mut Self::NonAtomicType {
+                #[cfg(loom)]
+                check_layout();
                 unsafe { &mut *(self as *mut Self as *mut Self::NonAtomicType) }
             }
 
@@ -50,7 +106,14 @@ macro_rules! impl_atomic_float {
 
             #[inline(always)]
             fn into_non_atomic_array<const N: usize>(data: [Self; N]) -> [Self::NonAtomicType; N] {
-                unsafe { *(data.as_ptr() as *const [Self::NonAtomicType; N]) }
+                #[cfg(not(loom))]
+                unsafe {
+                    *(data.as_ptr() as *const [Self::NonAtomicType; N])
+                }
+                #[cfg(loom)]
+                {
+                    data.map(Self::into_inner)
+                }
             }
 
             #[inline(always)]
@@ -60,11 +123,15 @@ macro_rules! impl_atomic_float {
 
             #[inline(always)]
             fn get_mut_slice(this: &mut [Self]) -> &mut [Self::NonAtomicType] {
+                #[cfg(loom)]
+                check_layout();
                 unsafe { core::mem::transmute::<&mut [Self], &mut [Self::NonAtomicType]>(this) }
             }
 
             #[inline(always)]
             fn from_mut_slice(this: &mut [Self::NonAtomicType]) -> &mut [Self] {
+                #[cfg(loom)]
+                check_layout();
                 unsafe { core::mem::transmute::<&mut [Self::NonAtomicType], &mut [Self]>(this) }
             }
 
@@ -72,6 +139,8 @@ macro_rules! impl_atomic_float {
             fn get_mut_array<const N: usize>(
                 this: &mut [Self; N],
             ) -> &mut [Self::NonAtomicType; N] {
+                #[cfg(loom)]
+                check_layout();
                 unsafe {
                     core::mem::transmute::<&mut [Self; N], &mut [Self::NonAtomicType; N]>(this)
                 }
@@ -80,6 +149,8 @@ macro_rules! impl_atomic_float {
             fn from_mut_array<const N: usize>(
                 this: &mut [Self::NonAtomicType; N],
             ) -> &mut [Self; N] {
+                #[cfg(loom)]
+                check_layout();
                 unsafe {
                     core::mem::transmute::<&mut [Self::NonAtomicType; N], &mut [Self; N]>(this)
                 }
@@ -173,6 +244,10 @@ macro_rules! impl_atomic_float {
                     .unwrap()
             }
         }
+        impl AtomicFloat for $atomic {
+            impl_total_min_max!($inner, $signed);
+        }
+
         impl AtomicFiniteRangeNumber for $atomic {
             #[inline(always)]
             fn fetch_saturating_add(
@@ -229,8 +304,8 @@ macro_rules! impl_atomic_float {
     };
 }
 
-impl_atomic_float!(f64, AtomicF64, AtomicU64);
-impl_atomic_float!(f32, AtomicF32, AtomicU32);
+impl_atomic_float!(f64, AtomicF64, AtomicU64, AtomicI64);
+impl_atomic_float!(f32, AtomicF32, AtomicU32, AtomicI32);
 
 /// Atomic [`half::f16`] based on [`AtomicU16`].
 #[cfg(feature = "half")]
@@ -244,7 +319,46 @@ pub struct AtomicF16(pub(crate) AtomicU16);
 #[repr(transparent)]
 pub struct AtomicBF16(pub(crate) AtomicU16);
 
+/// The `maximum` operation of IEEE 754-2019.
+#[inline(always)]
+fn maximum<F: Float>(a: F, b: F) -> F {
+    if a.is_nan() || b < a || (a == b && a.is_sign_positive()) {
+        a
+    } else {
+        b
+    }
+}
+
+/// The `minimum` operation of IEEE 754-2019.
+#[inline(always)]
+fn minimum<F: Float>(a: F, b: F) -> F {
+    if a.is_nan() || a < b || (a == b && a.is_sign_negative()) {
+        a
+    } else {
+        b
+    }
+}
+
 /// An atomic float type.
+///
+/// The methods applying an operation to the current value come in two
+/// flavors: the methods with prefix `fetch_` return the previous value, like
+/// [`AtomicNumber::fetch_add`], whereas the methods with suffix `_and_get`
+/// return the new value. The operation is applied using
+/// [`fetch_update`](Atomic::fetch_update), and the two orderings have the
+/// same meaning as in that method: `set_order` is the ordering of the
+/// successful store, and `fetch_order` is the ordering of the loads.
+///
+/// # Examples
+///
+/// ```rust
+/// use common_traits::{Atomic, AtomicF64, AtomicFloat};
+/// use core::sync::atomic::Ordering;
+///
+/// let x = AtomicF64::new(0.5);
+/// assert_eq!(x.fetch_recip(Ordering::AcqRel, Ordering::Acquire), 0.5);
+/// assert_eq!(x.to_degrees_and_get(Ordering::Relaxed, Ordering::Relaxed), 2.0_f64.to_degrees());
+/// ```
 pub trait AtomicFloat:
     AtomicFiniteRangeNumber
     + IsFloat<Float = True>
@@ -255,20 +369,35 @@ where
     Self::NonAtomicType: Float,
 {
     /// Returns `true` if this value is NaN.
-    fn is_nan(&self, order: Ordering) -> bool;
+    #[inline(always)]
+    fn is_nan(&self, order: Ordering) -> bool {
+        self.load(order).is_nan()
+    }
 
     /// Returns `true` if this value is positive infinity or negative infinity,
     /// and `false` otherwise.
-    fn is_infinite(&self, order: Ordering) -> bool;
+    #[inline(always)]
+    fn is_infinite(&self, order: Ordering) -> bool {
+        self.load(order).is_infinite()
+    }
 
     /// Returns `true` if this number is neither infinite nor NaN.
-    fn is_finite(&self, order: Ordering) -> bool;
+    #[inline(always)]
+    fn is_finite(&self, order: Ordering) -> bool {
+        self.load(order).is_finite()
+    }
 
     /// Returns `true` if the number is [subnormal](https://en.wikipedia.org/wiki/Subnormal_number).
-    fn is_subnormal(&self, order: Ordering) -> bool;
+    #[inline(always)]
+    fn is_subnormal(&self, order: Ordering) -> bool {
+        self.load(order).is_subnormal()
+    }
 
     /// Returns `true` if the number is neither zero, infinite, [subnormal](https://en.wikipedia.org/wiki/Subnormal_number), or NaN.
-    fn is_normal(&self, order: Ordering) -> bool;
+    #[inline(always)]
+    fn is_normal(&self, order: Ordering) -> bool {
+        self.load(order).is_normal()
+    }
 
     /// Returns `true` if `self` has a positive sign, including +0.0, NaNs with
     /// positive sign bit and positive infinity. Note that IEEE 754 doesn’t
@@ -277,7 +406,10 @@ where
     /// operations, the result of `is_sign_positive` on a NaN might produce an
     /// unexpected result in some cases. See explanation of NaN as a special
     /// value for more info.
-    fn is_sign_positive(&self, order: Ordering) -> bool;
+    #[inline(always)]
+    fn is_sign_positive(&self, order: Ordering) -> bool {
+        self.load(order).is_sign_positive()
+    }
 
     /// Returns `true` if `self` has a negative sign, including -0.0, NaNs with
     /// negative sign bit and negative infinity. Note that IEEE 754 doesn’t
@@ -286,64 +418,359 @@ where
     /// operations, the result of `is_sign_negative` on a NaN might produce an
     /// unexpected result in some cases. See explanation of NaN as a special
     /// value for more info.
-    fn is_sign_negative(&self, order: Ordering) -> bool;
+    #[inline(always)]
+    fn is_sign_negative(&self, order: Ordering) -> bool {
+        self.load(order).is_sign_negative()
+    }
 
     /// Returns the floating point category of the number. If only one property
     /// is going to be tested, it is generally faster to use the specific
     /// predicate instead.
-    fn classify(&self, order: Ordering) -> core::num::FpCategory;
+    #[inline(always)]
+    fn classify(&self, order: Ordering) -> core::num::FpCategory {
+        self.load(order).classify()
+    }
+
+    /// Maximum with the current value, propagating NaNs, returning the
+    /// previous value.
+    ///
+    /// Differently from [`AtomicNumber::fetch_max`], which follows
+    /// [`f64::max`], the new value is NaN if either value is NaN (in which
+    /// case the NaN is stored unchanged), and `-0.0` is considered smaller than
+    /// `+0.0`, as in the `maximum` operation of IEEE 754-2019.
+    ///
+    /// The ordering has the same meaning as in [`AtomicNumber::fetch_max`].
+    #[inline(always)]
+    fn fetch_maximum(&self, value: Self::NonAtomicType, order: Ordering) -> Self::NonAtomicType {
+        update(self, order, load_ordering(order), |x| maximum(x, value)).0
+    }
+
+    /// Minimum with the current value, propagating NaNs, returning the
+    /// previous value.
+    ///
+    /// Differently from [`AtomicNumber::fetch_min`], which follows
+    /// [`f64::min`], the new value is NaN if either value is NaN (in which
+    /// case the NaN is stored unchanged), and `-0.0` is considered smaller than
+    /// `+0.0`, as in the `minimum` operation of IEEE 754-2019.
+    ///
+    /// The ordering has the same meaning as in [`AtomicNumber::fetch_min`].
+    #[inline(always)]
+    fn fetch_minimum(&self, value: Self::NonAtomicType, order: Ordering) -> Self::NonAtomicType {
+        update(self, order, load_ordering(order), |x| minimum(x, value)).0
+    }
+
+    /// Maximum with the current value, ignoring NaNs, returning the previous
+    /// value.
+    ///
+    /// If exactly one of the values is NaN the new value is the other one,
+    /// and `-0.0` is considered smaller than `+0.0`, as in the
+    /// `maximumNumber` operation of IEEE 754-2019.
+    ///
+    /// The ordering has the same meaning as in [`AtomicNumber::fetch_max`].
+    #[inline(always)]
+    fn fetch_maximum_number(
+        &self,
+        value: Self::NonAtomicType,
+        order: Ordering,
+    ) -> Self::NonAtomicType {
+        update(self, order, load_ordering(order), |x| {
+            if x.is_nan() {
+                value
+            } else if value.is_nan() {
+                x
+            } else {
+                maximum(x, value)
+            }
+        })
+        .0
+    }
 
-    /// Atomically sets `self` to its reciprocal (inverse), `1/self`.
-    fn fetch_recip(&self, order: Ordering);
+    /// Minimum with the current value, ignoring NaNs, returning the previous
+    /// value.
+    ///
+    /// If exactly one of the values is NaN the new value is the other one,
+    /// and `-0.0` is considered smaller than `+0.0`, as in the
+    /// `minimumNumber` operation of IEEE 754-2019.
+    ///
+    /// The ordering has the same meaning as in [`AtomicNumber::fetch_min`].
+    #[inline(always)]
+    fn fetch_minimum_number(
+        &self,
+        value: Self::NonAtomicType,
+        order: Ordering,
+    ) -> Self::NonAtomicType {
+        update(self, order, load_ordering(order), |x| {
+            if x.is_nan() {
+                value
+            } else if value.is_nan() {
+                x
+            } else {
+                minimum(x, value)
+            }
+        })
+        .0
+    }
 
-    /// Atomically converts `self` from radians to degrees.
-    fn fetch_to_degrees(&self, order: Ordering);
+    /// Maximum with the current value in the order of
+    /// [`total_cmp`](Float::total_cmp), returning the previous value.
+    ///
+    /// The implementations in this crate compare the bit patterns of the
+    /// values using the native `fetch_max` or `fetch_min` of the underlying
+    /// atomic integer, as the total order coincides with the order of the bit
+    /// patterns as signed integers on positive values, and with the reverse
+    /// order of the bit patterns as unsigned integers on negative values.
+    ///
+    /// The ordering has the same meaning as in [`AtomicNumber::fetch_max`].
+    #[inline(always)]
+    fn fetch_max_total(&self, value: Self::NonAtomicType, order: Ordering) -> Self::NonAtomicType {
+        update(self, order, load_ordering(order), |x| {
+            if x.total_cmp(&value).is_lt() {
+                value
+            } else {
+                x
+            }
+        })
+        .0
+    }
 
-    /// Atomically converts `self` from degrees to radians.
-    fn fetch_to_radians(&self, order: Ordering);
+    /// Minimum with the current value in the order of
+    /// [`total_cmp`](Float::total_cmp), returning the previous value.
+    ///
+    /// The implementations in this crate use native integer operations as
+    /// described in [`fetch_max_total`](AtomicFloat::fetch_max_total).
+    ///
+    /// The ordering has the same meaning as in [`AtomicNumber::fetch_min`].
+    #[inline(always)]
+    fn fetch_min_total(&self, value: Self::NonAtomicType, order: Ordering) -> Self::NonAtomicType {
+        update(self, order, load_ordering(order), |x| {
+            if x.total_cmp(&value).is_gt() {
+                value
+            } else {
+                x
+            }
+        })
+        .0
+    }
+
+    /// Atomically sets `self` to its reciprocal (inverse), `1/self`, returning the
+    /// previous value.
+    #[inline(always)]
+    fn fetch_recip(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.recip()).0
+    }
+
+    /// Like [`fetch_recip`](AtomicFloat::fetch_recip), but returns the new
+    /// value.
+    #[inline(always)]
+    fn recip_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.recip()).1
+    }
+
+    /// Atomically converts `self` from radians to degrees, returning the previous
+    /// value.
+    #[inline(always)]
+    fn fetch_to_degrees(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.to_degrees()).0
+    }
+
+    /// Like [`fetch_to_degrees`](AtomicFloat::fetch_to_degrees), but returns the new
+    /// value.
+    #[inline(always)]
+    fn to_degrees_and_get(
+        &self,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.to_degrees()).1
+    }
+
+    /// Atomically converts `self` from degrees to radians, returning the previous
+    /// value.
+    #[inline(always)]
+    fn fetch_to_radians(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.to_radians()).0
+    }
+
+    /// Like [`fetch_to_radians`](AtomicFloat::fetch_to_radians), but returns the new
+    /// value.
+    #[inline(always)]
+    fn to_radians_and_get(
+        &self,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.to_radians()).1
+    }
+
+    /// Atomically sets `self` to the result of Euclidean division by `rhs`,
+    /// returning the previous value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn fetch_div_euclid(
+        &self,
+        rhs: Self::NonAtomicType,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.div_euclid(rhs)).0
+    }
+
+    /// Like [`fetch_div_euclid`](AtomicFloat::fetch_div_euclid), but returns the new
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn div_euclid_and_get(
+        &self,
+        rhs: Self::NonAtomicType,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.div_euclid(rhs)).1
+    }
+
+    /// Atomically sets `self` to the least non-negative remainder of
+    /// `self (mod rhs)`, returning the previous value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn fetch_rem_euclid(
+        &self,
+        rhs: Self::NonAtomicType,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.rem_euclid(rhs)).0
+    }
+
+    /// Like [`fetch_rem_euclid`](AtomicFloat::fetch_rem_euclid), but returns the new
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn rem_euclid_and_get(
+        &self,
+        rhs: Self::NonAtomicType,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.rem_euclid(rhs)).1
+    }
+
+    /// Atomically sets `self` to the largest integer less than or equal to `self`,
+    /// returning the previous value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn fetch_floor(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.floor()).0
+    }
 
-    /// Atomically sets `self` to the result of Euclidean division by `rhs`.
+    /// Like [`fetch_floor`](AtomicFloat::fetch_floor), but returns the new
+    /// value.
     #[cfg(feature = "std")]
-    fn fetch_div_euclid(&self, rhs: Self::NonAtomicType, order: Ordering);
+    #[inline(always)]
+    fn floor_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.floor()).1
+    }
 
-    /// Atomically sets `self` to the least non-negative remainder of `self (mod rhs)`.
+    /// Atomically sets `self` to the smallest integer greater than or equal to
+    /// `self`, returning the previous value.
     #[cfg(feature = "std")]
-    fn fetch_rem_euclid(&self, rhs: Self::NonAtomicType, order: Ordering);
+    #[inline(always)]
+    fn fetch_ceil(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.ceil()).0
+    }
 
-    /// Atomically sets `self` to the largest integer less than or equal to `self`.
+    /// Like [`fetch_ceil`](AtomicFloat::fetch_ceil), but returns the new
+    /// value.
     #[cfg(feature = "std")]
-    fn fetch_floor(&self, order: Ordering);
+    #[inline(always)]
+    fn ceil_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.ceil()).1
+    }
 
-    /// Atomically sets `self` to the smallest integer greater than or equal to `self`.
+    /// Atomically sets `self` to the nearest integer, returning the previous
+    /// value. Rounds half-way cases away from `0.0`.
     #[cfg(feature = "std")]
-    fn fetch_ceil(&self, order: Ordering);
+    #[inline(always)]
+    fn fetch_round(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.round()).0
+    }
 
-    /// Atomically sets `self` to the nearest integer. Rounds half-way cases away from `0.0`.
+    /// Like [`fetch_round`](AtomicFloat::fetch_round), but returns the new
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn round_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.round()).1
+    }
+
+    /// Atomically sets `self` to its integer part, returning the previous
+    /// value. This means that non-integer numbers are always truncated towards
+    /// zero.
     #[cfg(feature = "std")]
-    fn fetch_round(&self, order: Ordering);
+    #[inline(always)]
+    fn fetch_trunc(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.trunc()).0
+    }
 
-    /// Atomically sets `self` to its integer part. This means that non-integer numbers
-    /// are always truncated towards zero.
+    /// Like [`fetch_trunc`](AtomicFloat::fetch_trunc), but returns the new
+    /// value.
     #[cfg(feature = "std")]
-    fn fetch_trunc(&self, order: Ordering);
+    #[inline(always)]
+    fn trunc_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.trunc()).1
+    }
 
-    /// Atomically sets `self` to its fractional part.
+    /// Atomically sets `self` to its fractional part, returning the previous value.
     #[cfg(feature = "std")]
-    fn fetch_fract(&self, order: Ordering);
+    #[inline(always)]
+    fn fetch_fract(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.fract()).0
+    }
 
-    /// Atomically sets `self` to its absolute value.
+    /// Like [`fetch_fract`](AtomicFloat::fetch_fract), but returns the new
+    /// value.
     #[cfg(feature = "std")]
-    fn fetch_abs(&self, order: Ordering);
+    #[inline(always)]
+    fn fract_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.fract()).1
+    }
 
-    /// Atomically sets `self` to the sign of its current value.
+    /// Atomically sets `self` to its absolute value, returning the previous value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn fetch_abs(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.abs()).0
+    }
+
+    /// Like [`fetch_abs`](AtomicFloat::fetch_abs), but returns the new
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn abs_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.abs()).1
+    }
+
+    /// Atomically sets `self` to the sign of its current value, returning the
+    /// previous value.
     ///
     /// - `1.0` if the number is positive, `+0.0` or `INFINITY`
     /// - `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
     /// - `NaN` if the number is `NaN`
     #[cfg(feature = "std")]
-    fn fetch_signum(&self, order: Ordering);
+    #[inline(always)]
+    fn fetch_signum(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.signum()).0
+    }
+
+    /// Like [`fetch_signum`](AtomicFloat::fetch_signum), but returns the new
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn signum_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.signum()).1
+    }
 
-    /// Atomically sets `self` to the magnitude of `self` with the sign of `sign`.
+    /// Atomically sets `self` to the magnitude of `self` with the sign of `sign`,
+    /// returning the previous value.
     ///
     /// Equal to `self` if the sign of `self` and `sign` are the same, otherwise equal
     /// to `-self`. If `self` is a NaN, then a NaN with the sign bit of `sign` is
@@ -351,40 +778,151 @@ where
     /// arithmetical operations is not generally guaranteed. See explanation of
     /// NaN as a special value for more info.
     #[cfg(feature = "std")]
-    fn fetch_copysign(&self, sign: Self::NonAtomicType, order: Ordering);
-
-    /// Atomically raises `self` to an integer power.
+    #[inline(always)]
+    fn fetch_copysign(
+        &self,
+        sign: Self::NonAtomicType,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.copysign(sign)).0
+    }
+
+    /// Like [`fetch_copysign`](AtomicFloat::fetch_copysign), but returns the new
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn copysign_and_get(
+        &self,
+        sign: Self::NonAtomicType,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.copysign(sign)).1
+    }
+
+    /// Atomically raises `self` to an integer power, returning the previous value.
     ///
     /// Using this function is generally faster than using
     /// [`fetch_powf`](`AtomicFloat::fetch_powf`). It might have a different
     /// sequence of rounding operations, so the results are not guaranteed
     /// to agree.
     #[cfg(feature = "std")]
-    fn fetch_powi(&self, n: isize, order: Ordering);
-
-    /// Atomically raises `self` to a floating point power.
+    #[inline(always)]
+    fn fetch_powi(
+        &self,
+        n: isize,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.powi(n)).0
+    }
+
+    /// Like [`fetch_powi`](AtomicFloat::fetch_powi), but returns the new
+    /// value.
     #[cfg(feature = "std")]
-    fn fetch_powf(&self, n: Self::NonAtomicType, order: Ordering);
-
-    /// Atomically sets `self` to its square root.
+    #[inline(always)]
+    fn powi_and_get(
+        &self,
+        n: isize,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.powi(n)).1
+    }
+
+    /// Atomically raises `self` to a floating point power, returning the previous
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn fetch_powf(
+        &self,
+        n: Self::NonAtomicType,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.powf(n)).0
+    }
+
+    /// Like [`fetch_powf`](AtomicFloat::fetch_powf), but returns the new
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn powf_and_get(
+        &self,
+        n: Self::NonAtomicType,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.powf(n)).1
+    }
+
+    /// Atomically sets `self` to its square root, returning the previous value.
     ///
     /// Sets `self` to `NaN` if it is a negative number other than `-0.0`.
     #[cfg(feature = "std")]
-    fn fetch_sqrt(&self, order: Ordering);
+    #[inline(always)]
+    fn fetch_sqrt(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.sqrt()).0
+    }
+
+    /// Like [`fetch_sqrt`](AtomicFloat::fetch_sqrt), but returns the new
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn sqrt_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.sqrt()).1
+    }
+
+    /// Atomically sets `self` to `e^(self)` (the exponential function), returning
+    /// the previous value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn fetch_exp(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.exp()).0
+    }
+
+    /// Like [`fetch_exp`](AtomicFloat::fetch_exp), but returns the new
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn exp_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.exp()).1
+    }
 
-    /// Atomically sets `self` to `e^(self)` (the exponential function).
+    /// Atomically sets `self` to `2^(self)`, returning the previous value.
     #[cfg(feature = "std")]
-    fn fetch_exp(&self, order: Ordering);
+    #[inline(always)]
+    fn fetch_exp2(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.exp2()).0
+    }
 
-    /// Atomically sets `self` to `2^(self)`.
+    /// Like [`fetch_exp2`](AtomicFloat::fetch_exp2), but returns the new
+    /// value.
     #[cfg(feature = "std")]
-    fn fetch_exp2(&self, order: Ordering);
+    #[inline(always)]
+    fn exp2_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.exp2()).1
+    }
 
-    /// Atomically sets `self` to its natural logarithm.
+    /// Atomically sets `self` to its natural logarithm, returning the previous
+    /// value.
     #[cfg(feature = "std")]
-    fn fetch_ln(&self, order: Ordering);
+    #[inline(always)]
+    fn fetch_ln(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.ln()).0
+    }
 
-    /// Atomically sets `self` to its logarithm with respect to an arbitrary base.
+    /// Like [`fetch_ln`](AtomicFloat::fetch_ln), but returns the new
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn ln_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.ln()).1
+    }
+
+    /// Atomically sets `self` to its logarithm with respect to an arbitrary base,
+    /// returning the previous value.
     ///
     /// The result might not be correctly rounded owing to implementation
     /// details; [`fetch_log2`](`AtomicFloat::fetch_log2`) can produce more
@@ -392,80 +930,299 @@ where
     /// [`fetch_log10`](`AtomicFloat::fetch_log10`) can produce more accurate
     /// results for base 10.
     #[cfg(feature = "std")]
-    fn fetch_log(&self, base: Self::NonAtomicType, order: Ordering);
+    #[inline(always)]
+    fn fetch_log(
+        &self,
+        base: Self::NonAtomicType,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.log(base)).0
+    }
+
+    /// Like [`fetch_log`](AtomicFloat::fetch_log), but returns the new
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn log_and_get(
+        &self,
+        base: Self::NonAtomicType,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.log(base)).1
+    }
+
+    /// Atomically sets `self` to its base 2 logarithm, returning the previous
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn fetch_log2(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.log2()).0
+    }
+
+    /// Like [`fetch_log2`](AtomicFloat::fetch_log2), but returns the new
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn log2_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.log2()).1
+    }
+
+    /// Atomically sets `self` to its base 10 logarithm, returning the previous
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn fetch_log10(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.log10()).0
+    }
+
+    /// Like [`fetch_log10`](AtomicFloat::fetch_log10), but returns the new
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn log10_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.log10()).1
+    }
+
+    /// Atomically sets `self` to its cube root, returning the previous value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn fetch_cbrt(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.cbrt()).0
+    }
+
+    /// Like [`fetch_cbrt`](AtomicFloat::fetch_cbrt), but returns the new
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn cbrt_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.cbrt()).1
+    }
 
-    /// Atomically sets `self` to its base 2 logarithm.
+    /// Atomically sets `self` to its sine (in radians), returning the previous
+    /// value.
     #[cfg(feature = "std")]
-    fn fetch_log2(&self, order: Ordering);
+    #[inline(always)]
+    fn fetch_sin(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.sin()).0
+    }
 
-    /// Atomically sets `self` to its base 10 logarithm.
+    /// Like [`fetch_sin`](AtomicFloat::fetch_sin), but returns the new
+    /// value.
     #[cfg(feature = "std")]
-    fn fetch_log10(&self, order: Ordering);
+    #[inline(always)]
+    fn sin_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.sin()).1
+    }
 
-    /// Atomically sets `self` to its cube root.
+    /// Atomically sets `self` to its cosine (in radians), returning the previous
+    /// value.
     #[cfg(feature = "std")]
-    fn fetch_cbrt(&self, order: Ordering);
+    #[inline(always)]
+    fn fetch_cos(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.cos()).0
+    }
 
-    /// Atomically sets `self` to its sine (in radians).
+    /// Like [`fetch_cos`](AtomicFloat::fetch_cos), but returns the new
+    /// value.
     #[cfg(feature = "std")]
-    fn fetch_sin(&self, order: Ordering);
+    #[inline(always)]
+    fn cos_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.cos()).1
+    }
 
-    /// Atomically sets `self` to its cosine (in radians).
+    /// Atomically sets `self` to its tangent (in radians), returning the previous
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn fetch_tan(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.tan()).0
+    }
+
+    /// Like [`fetch_tan`](AtomicFloat::fetch_tan), but returns the new
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn tan_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.tan()).1
+    }
+
+    /// Atomically sets `self` to its arcsine, returning the previous value.
+    /// The resulting value is in radians in the range [-pi/2, pi/2] or NaN if
+    /// the number is outside the range [-1, 1].
     #[cfg(feature = "std")]
-    fn fetch_cos(&self, order: Ordering);
+    #[inline(always)]
+    fn fetch_asin(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.asin()).0
+    }
 
-    /// Atomically sets `self` to its tangent (in radians).
+    /// Like [`fetch_asin`](AtomicFloat::fetch_asin), but returns the new
+    /// value.
     #[cfg(feature = "std")]
-    fn fetch_tan(&self, order: Ordering);
+    #[inline(always)]
+    fn asin_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.asin()).1
+    }
+
+    /// Atomically sets `self` to its arccosine, returning the previous value.
+    /// The resulting value is in radians in the range [0, pi] or NaN if the
+    /// number is outside the range [-1, 1].
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn fetch_acos(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.acos()).0
+    }
 
-    /// Atomically sets `self` to its arcsine. The resulting value is in radians
-    /// in the range [-pi/2, pi/2] or NaN if the number is outside the range
-    /// [-1, 1].
+    /// Like [`fetch_acos`](AtomicFloat::fetch_acos), but returns the new
+    /// value.
     #[cfg(feature = "std")]
-    fn fetch_asin(&self, order: Ordering);
+    #[inline(always)]
+    fn acos_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.acos()).1
+    }
 
-    /// Atomically sets `self` to its arccosine. The resulting value is in
-    /// radians in the range [0, pi] or NaN if the number is outside the range
-    /// [-1, 1].
+    /// Atomically sets `self` to its arctangent, returning the previous value.
+    /// The resulting value is in radians in the range [-pi/2, pi/2].
     #[cfg(feature = "std")]
-    fn fetch_acos(&self, order: Ordering);
+    #[inline(always)]
+    fn fetch_atan(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.atan()).0
+    }
 
-    /// Atomically sets `self` to its arctangent. The resulting value is in
-    /// radians in the range [-pi/2, pi/2].
+    /// Like [`fetch_atan`](AtomicFloat::fetch_atan), but returns the new
+    /// value.
     #[cfg(feature = "std")]
-    fn fetch_atan(&self, order: Ordering);
+    #[inline(always)]
+    fn atan_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.atan()).1
+    }
 
     /// Atomically sets `self` to `e^(self) - 1` in a way that is accurate
-    /// even if the number is close to zero.
+    /// even if the number is close to zero, returning the previous value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn fetch_exp_m1(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.exp_m1()).0
+    }
+
+    /// Like [`fetch_exp_m1`](AtomicFloat::fetch_exp_m1), but returns the new
+    /// value.
     #[cfg(feature = "std")]
-    fn fetch_exp_m1(&self, order: Ordering);
+    #[inline(always)]
+    fn exp_m1_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.exp_m1()).1
+    }
 
     /// Atomically sets `self` to `ln(1+self)` (natural logarithm) more
-    /// accurately than if the operations were performed separately.
+    /// accurately than if the operations were performed separately, returning the
+    /// previous value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn fetch_ln_1p(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.ln_1p()).0
+    }
+
+    /// Like [`fetch_ln_1p`](AtomicFloat::fetch_ln_1p), but returns the new
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn ln_1p_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.ln_1p()).1
+    }
+
+    /// Atomically sets `self` to its hyperbolic sine, returning the previous value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn fetch_sinh(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.sinh()).0
+    }
+
+    /// Like [`fetch_sinh`](AtomicFloat::fetch_sinh), but returns the new
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn sinh_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.sinh()).1
+    }
+
+    /// Atomically sets `self` to its hyperbolic cosine, returning the previous
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn fetch_cosh(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.cosh()).0
+    }
+
+    /// Like [`fetch_cosh`](AtomicFloat::fetch_cosh), but returns the new
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn cosh_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.cosh()).1
+    }
+
+    /// Atomically sets `self` to its hyperbolic tangent, returning the previous
+    /// value.
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn fetch_tanh(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.tanh()).0
+    }
+
+    /// Like [`fetch_tanh`](AtomicFloat::fetch_tanh), but returns the new
+    /// value.
     #[cfg(feature = "std")]
-    fn fetch_ln_1p(&self, order: Ordering);
+    #[inline(always)]
+    fn tanh_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.tanh()).1
+    }
 
-    /// Atomically sets `self` to its hyperbolic sine.
+    /// Atomically sets `self` to its inverse hyperbolic sine, returning the
+    /// previous value.
     #[cfg(feature = "std")]
-    fn fetch_sinh(&self, order: Ordering);
+    #[inline(always)]
+    fn fetch_asinh(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.asinh()).0
+    }
 
-    /// Atomically sets `self` to its hyperbolic cosine.
+    /// Like [`fetch_asinh`](AtomicFloat::fetch_asinh), but returns the new
+    /// value.
     #[cfg(feature = "std")]
-    fn fetch_cosh(&self, order: Ordering);
+    #[inline(always)]
+    fn asinh_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.asinh()).1
+    }
 
-    /// Atomically sets `self` to its hyperbolic tangent.
+    /// Atomically sets `self` to its inverse hyperbolic cosine, returning the
+    /// previous value.
     #[cfg(feature = "std")]
-    fn fetch_tanh(&self, order: Ordering);
+    #[inline(always)]
+    fn fetch_acosh(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.acosh()).0
+    }
 
-    /// Atomically sets `self` to its inverse hyperbolic sine.
+    /// Like [`fetch_acosh`](AtomicFloat::fetch_acosh), but returns the new
+    /// value.
     #[cfg(feature = "std")]
-    fn fetch_asinh(&self, order: Ordering);
+    #[inline(always)]
+    fn acosh_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.acosh()).1
+    }
 
-    /// Atomically sets `self` to its inverse hyperbolic cosine.
+    /// Atomically sets `self` to its inverse hyperbolic tangent, returning the
+    /// previous value.
     #[cfg(feature = "std")]
-    fn fetch_acosh(&self, order: Ordering);
+    #[inline(always)]
+    fn fetch_atanh(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.atanh()).0
+    }
 
-    /// Atomically sets `self` to its inverse hyperbolic tangent.
+    /// Like [`fetch_atanh`](AtomicFloat::fetch_atanh), but returns the new
+    /// value.
     #[cfg(feature = "std")]
-    fn fetch_atanh(&self, order: Ordering);
+    #[inline(always)]
+    fn atanh_and_get(&self, set_order: Ordering, fetch_order: Ordering) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.atanh()).1
+    }
 }
@@ -0,0 +1,458 @@
+use crate::sync::AtomicU64;
+use crate::{AtomicUnsignedInt, FastRange, Float, Number, To, ToBytes, UnsignedInt};
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+use core::sync::atomic::Ordering;
+
+/// The layout of the buckets of an [`AtomicHistogram`].
+///
+/// Every layout covers all `u64` values, so no value is ever discarded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BucketLayout {
+    /// `len` buckets of `width` values each, starting at `min`.
+    ///
+    /// Values smaller than `min` are counted in the first bucket, and values
+    /// larger than the end of the last bucket are counted in the last bucket,
+    /// which thus extends to [`u64::MAX`].
+    Linear {
+        /// The smallest value of the first bucket.
+        min: u64,
+        /// The number of values in a bucket; it must be positive.
+        width: u64,
+        /// The number of buckets; it must be positive.
+        len: usize,
+    },
+    /// A bucket for zero and a bucket for each interval
+    /// [2<sup>*k*</sup> . . 2<sup>*k* + 1</sup>), for a total of 65 buckets.
+    Log2,
+    /// A bucket for each value smaller than 2<sup>`precision`</sup>, and then
+    /// 2<sup>`precision`</sup> buckets of equal width for each interval
+    /// [2<sup>*k*</sup> . . 2<sup>*k* + 1</sup>), as in
+    /// [HdrHistogram](http://hdrhistogram.org/).
+    ///
+    /// The relative error of a value estimated from its bucket is at most
+    /// 2<sup>−`precision`</sup>, and there are
+    /// (65 − `precision`) · 2<sup>`precision`</sup> buckets. `precision` must
+    /// be at most 16.
+    Hdr {
+        /// The number of significant bits of the values.
+        precision: u32,
+    },
+}
+
+impl BucketLayout {
+    /// Panics if the parameters of the layout are invalid.
+    fn check(&self) {
+        match *self {
+            Self::Linear { width, len, .. } => {
+                assert!(width > 0, "the width of linear buckets must be positive");
+                assert!(len > 0, "the number of linear buckets must be positive");
+            }
+            Self::Log2 => {}
+            Self::Hdr { precision } => {
+                assert!(
+                    precision <= 16,
+                    "the precision of HDR buckets must be at most 16, got {precision}"
+                );
+            }
+        }
+    }
+
+    /// Returns the number of buckets.
+    #[inline(always)]
+    pub fn num_buckets(&self) -> usize {
+        match *self {
+            Self::Linear { len, .. } => len,
+            Self::Log2 => 65,
+            Self::Hdr { precision } => (65 - precision as usize) << precision,
+        }
+    }
+
+    /// Returns the index of the bucket of `value`, given the mask of the
+    /// width of linear buckets (zero for a unit width).
+    #[inline(always)]
+    fn index_with_mask(&self, value: u64, mask: u128) -> usize {
+        match *self {
+            Self::Linear { min, len, .. } => {
+                let offset = value.saturating_sub(min);
+                // fast_div_mask requires a divisor larger than one
+                let index = if mask == 0 {
+                    offset
+                } else {
+                    offset.fast_div_mask(mask)
+                };
+                Ord::min(index, len as u64 - 1) as usize
+            }
+            Self::Log2 => {
+                if value == 0 {
+                    0
+                } else {
+                    UnsignedInt::ilog2(value) as usize + 1
+                }
+            }
+            Self::Hdr { precision } => {
+                let sub_buckets = 1_u64 << precision;
+                if value < sub_buckets {
+                    value as usize
+                } else {
+                    let exp = UnsignedInt::ilog2(value) - precision;
+                    // the leading one of the shifted value accounts for the
+                    // buckets of small values
+                    (((exp as u64) << precision) + (value >> exp)) as usize
+                }
+            }
+        }
+    }
+
+    /// Returns the index of the bucket of the integer part of `value`, given
+    /// the mask of the width of linear buckets, computing it from the
+    /// exponent and the mantissa of the IEEE representation of `value`.
+    ///
+    /// Negative values and NaNs are mapped to the bucket of zero, and values
+    /// of at least 2<sup>64</sup> to the bucket of [`u64::MAX`].
+    #[inline(always)]
+    fn float_index_with_mask<F: Float + ToBytes>(&self, value: F, mask: u128) -> usize {
+        if value.is_nan() || value.is_sign_negative() {
+            return self.index_with_mask(0, mask);
+        }
+        if value.is_infinite() {
+            return self.index_with_mask(u64::MAX, mask);
+        }
+        let mut bits = 0_u64;
+        for (i, &byte) in value.to_le_bytes().as_ref().iter().enumerate() {
+            bits |= (byte as u64) << (8 * i);
+        }
+        let mantissa_bits = F::MANTISSA_DIGITS - 1;
+        let mantissa = bits & ((1 << mantissa_bits) - 1);
+        // the sign bit is zero
+        let exp = (bits >> mantissa_bits) as i32 - (F::MAX_EXP - 1);
+        if exp < 0 {
+            // smaller than one, including zero and subnormal values
+            return self.index_with_mask(0, mask);
+        }
+        if exp >= 64 {
+            return self.index_with_mask(u64::MAX, mask);
+        }
+        // the binary logarithm of the integer part
+        let exp = exp as u32;
+        match *self {
+            Self::Log2 => exp as usize + 1,
+            Self::Hdr { precision } if exp >= precision => {
+                // the most significant bits of the mantissa select the bucket
+                // in the interval of the exponent, as in index_with_mask
+                let sub_bucket = if mantissa_bits >= precision {
+                    mantissa >> (mantissa_bits - precision)
+                } else {
+                    mantissa << (precision - mantissa_bits)
+                };
+                (((exp - precision + 1) as usize) << precision) | sub_bucket as usize
+            }
+            _ => {
+                // small values of HDR layouts and linear layouts need the
+                // integer part
+                let significand = mantissa | (1 << mantissa_bits);
+                let int = if exp >= mantissa_bits {
+                    significand << (exp - mantissa_bits)
+                } else {
+                    significand >> (mantissa_bits - exp)
+                };
+                self.index_with_mask(int, mask)
+            }
+        }
+    }
+
+    /// Returns the mask for [`FastRange::fast_div_mask`] of the width of
+    /// linear buckets, or zero for unit width and other layouts.
+    #[inline(always)]
+    fn mask(&self) -> u128 {
+        match *self {
+            Self::Linear { width, .. } if width > 1 => width.compute_mask_fast(),
+            _ => 0,
+        }
+    }
+
+    /// Returns the index of the bucket of `value`.
+    #[inline(always)]
+    pub fn index(&self, value: u64) -> usize {
+        self.index_with_mask(value, self.mask())
+    }
+
+    /// Returns the range of values of the bucket of given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not smaller than the number of buckets.
+    pub fn range(&self, index: usize) -> RangeInclusive<u64> {
+        assert!(
+            index < self.num_buckets(),
+            "bucket index {index} out of range for a layout with {} buckets",
+            self.num_buckets()
+        );
+        match *self {
+            Self::Linear { min, width, len } => {
+                let start = if index == 0 {
+                    0
+                } else {
+                    min.saturating_add(width.saturating_mul(index as u64))
+                };
+                let end = if index == len - 1 {
+                    u64::MAX
+                } else {
+                    min.saturating_add(width.saturating_mul(index as u64 + 1)) - 1
+                };
+                start..=end
+            }
+            Self::Log2 => match index {
+                0 => 0..=0,
+                _ => {
+                    let start = 1_u64 << (index - 1);
+                    start..=start + (start - 1)
+                }
+            },
+            Self::Hdr { precision } => {
+                let index = index as u64;
+                if index < 1 << precision {
+                    index..=index
+                } else {
+                    let exp = (index >> precision) - 1;
+                    let start = (index - (exp << precision)) << exp;
+                    start..=start + ((1 << exp) - 1)
+                }
+            }
+        }
+    }
+}
+
+/// A histogram whose buckets are atomic counters, so that values can be
+/// recorded concurrently.
+///
+/// The counters can be of any [`AtomicUnsignedInt`] type (by default,
+/// [`AtomicU64`]), and buckets are placed according to a [`BucketLayout`].
+/// Values are `u64`; floating-point values can be recorded with
+/// [`record_float`](AtomicHistogram::record_float), so they should be
+/// expressed in a unit providing enough resolution (e.g., latencies in
+/// microseconds rather than seconds).
+///
+/// Methods involving all buckets, such as
+/// [`quantile`](AtomicHistogram::quantile), work on a snapshot of the
+/// counters that is not atomic as a whole: it might include only some of the
+/// values recorded concurrently.
+///
+/// # Examples
+///
+/// ```rust
+/// use common_traits::{AtomicHistogram, BucketLayout};
+/// use core::sync::atomic::Ordering;
+///
+/// let latencies: AtomicHistogram = AtomicHistogram::new(BucketLayout::Hdr { precision: 7 });
+/// std::thread::scope(|s| {
+///     for t in 0..4 {
+///         let latencies = &latencies;
+///         s.spawn(move || {
+///             for i in 0..1000 {
+///                 latencies.record(t * 1000 + i, Ordering::Relaxed);
+///             }
+///         });
+///     }
+/// });
+/// assert_eq!(latencies.total(Ordering::Relaxed), 4000);
+/// let median = latencies.percentile(50.0, Ordering::Relaxed).unwrap();
+/// assert!(median.abs_diff(2000) <= 2000 / 128);
+/// ```
+pub struct AtomicHistogram<A = AtomicU64> {
+    layout: BucketLayout,
+    mask: u128,
+    buckets: Box<[A]>,
+}
+
+impl<A: AtomicUnsignedInt> AtomicHistogram<A>
+where
+    A::NonAtomicType: UnsignedInt + To<u128>,
+{
+    /// Creates a new empty histogram with the given layout.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters of the layout are invalid.
+    pub fn new(layout: BucketLayout) -> Self {
+        layout.check();
+        Self {
+            layout,
+            mask: layout.mask(),
+            buckets: (0..layout.num_buckets())
+                .map(|_| A::new(A::NonAtomicType::ZERO))
+                .collect(),
+        }
+    }
+
+    /// Returns the layout of the buckets.
+    #[inline(always)]
+    pub fn layout(&self) -> BucketLayout {
+        self.layout
+    }
+
+    /// Returns the number of buckets.
+    #[inline(always)]
+    pub fn num_buckets(&self) -> usize {
+        self.buckets.len()
+    }
+
+    /// Records a value.
+    ///
+    /// The ordering has the same meaning as in
+    /// [`AtomicNumber::fetch_add`](crate::AtomicNumber::fetch_add), but it
+    /// applies only to the counter of the bucket.
+    #[inline(always)]
+    pub fn record(&self, value: u64, order: Ordering) {
+        self.record_n(value, A::NonAtomicType::ONE, order);
+    }
+
+    /// Records a value `count` times.
+    ///
+    /// Counters wrap around on overflow.
+    #[inline(always)]
+    pub fn record_n(&self, value: u64, count: A::NonAtomicType, order: Ordering) {
+        let index = self.layout.index_with_mask(value, self.mask);
+        self.buckets[index].fetch_add(count, order);
+    }
+
+    /// Records a floating-point value, truncated towards zero.
+    ///
+    /// The bucket is computed directly from the exponent and the mantissa of
+    /// the IEEE representation of the value, with no conversion to an
+    /// integer: for [logarithmic](BucketLayout::Log2) layouts the exponent
+    /// selects the bucket, and for [HDR](BucketLayout::Hdr) layouts the
+    /// exponent selects the interval and the `precision` most significant
+    /// bits of the mantissa select the bucket within it.
+    ///
+    /// Negative values and NaNs are recorded as zero, and values that are too
+    /// large are recorded as [`u64::MAX`].
+    #[inline(always)]
+    pub fn record_float<F: Float + ToBytes>(&self, value: F, order: Ordering) {
+        let index = self.layout.float_index_with_mask(value, self.mask);
+        self.buckets[index].fetch_add(A::NonAtomicType::ONE, order);
+    }
+
+    /// Returns the counter of the bucket of given index.
+    #[inline(always)]
+    pub fn count(&self, index: usize, order: Ordering) -> A::NonAtomicType {
+        self.buckets[index].load(order)
+    }
+
+    /// Returns a snapshot of the counters of all buckets.
+    pub fn counts(&self, order: Ordering) -> Vec<A::NonAtomicType> {
+        self.buckets
+            .iter()
+            .map(|bucket| bucket.load(order))
+            .collect()
+    }
+
+    /// Returns the total number of recorded values.
+    pub fn total(&self, order: Ordering) -> u128 {
+        self.buckets
+            .iter()
+            .map(|bucket| bucket.load(order).to())
+            .sum()
+    }
+
+    /// Returns the largest value of the bucket containing the `q`-quantile
+    /// of the recorded values, or `None` if the histogram is empty.
+    ///
+    /// The result is thus an overestimate bounded by the width of the
+    /// bucket, and it is [`u64::MAX`] if the quantile falls in the last
+    /// bucket of a [linear layout](BucketLayout::Linear).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `q` is not in [0 . . 1].
+    pub fn quantile(&self, q: f64, order: Ordering) -> Option<u64> {
+        assert!(
+            (0.0..=1.0).contains(&q),
+            "the quantile {q} is not in [0 . . 1]"
+        );
+        let counts = self.counts(order);
+        let total: u128 = counts.iter().map(|&count| count.to()).sum();
+        if total == 0 {
+            return None;
+        }
+        // the rank of the quantile, that is, ⌈q · total⌉, but at least one
+        let scaled = q * total as f64;
+        let mut rank = scaled as u128;
+        if (rank as f64) < scaled {
+            rank += 1;
+        }
+        let rank = Ord::clamp(rank, 1, total);
+
+        let mut cumulative = 0_u128;
+        for (index, &count) in counts.iter().enumerate() {
+            cumulative += count.to();
+            if cumulative >= rank {
+                return Some(*self.layout.range(index).end());
+            }
+        }
+        unreachable!("the sum of the counters is {total}")
+    }
+
+    /// Returns the largest value of the bucket containing the `p`-th
+    /// percentile of the recorded values, or `None` if the histogram is
+    /// empty.
+    ///
+    /// This is equivalent to [`quantile`](AtomicHistogram::quantile) with
+    /// argument `p / 100`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not in [0 . . 100].
+    #[inline(always)]
+    pub fn percentile(&self, p: f64, order: Ordering) -> Option<u64> {
+        assert!(
+            (0.0..=100.0).contains(&p),
+            "the percentile {p} is not in [0 . . 100]"
+        );
+        self.quantile(p / 100.0, order)
+    }
+
+    /// Adds to the counters of this histogram a snapshot of the counters of
+    /// another histogram.
+    ///
+    /// `load_order` is used to read the counters of `other`, and
+    /// `add_order` to update the counters of this histogram.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the histograms have different layouts.
+    pub fn merge(&self, other: &Self, load_order: Ordering, add_order: Ordering) {
+        assert_eq!(
+            self.layout, other.layout,
+            "cannot merge histograms with different layouts"
+        );
+        for (bucket, other) in self.buckets.iter().zip(other.buckets.iter()) {
+            let count = other.load(load_order);
+            if count != A::NonAtomicType::ZERO {
+                bucket.fetch_add(count, add_order);
+            }
+        }
+    }
+
+    /// Sets all counters to zero.
+    pub fn reset(&self, order: Ordering) {
+        for bucket in self.buckets.iter() {
+            bucket.store(A::NonAtomicType::ZERO, order);
+        }
+    }
+}
+
+impl<A: AtomicUnsignedInt> core::fmt::Debug for AtomicHistogram<A>
+where
+    A::NonAtomicType: UnsignedInt + To<u128> + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AtomicHistogram")
+            .field("layout", &self.layout)
+            .field("counts", &self.counts(Ordering::Relaxed))
+            .finish()
+    }
+}
@@ -1,5 +1,6 @@
 use core::sync::atomic::Ordering;
 
+use crate::atomic::update;
 use crate::{AtomicNumber, False, Integer, IsSigned, SignedInt, True, UnsignedInt};
 
 /// An atomic integer type.
@@ -82,6 +83,147 @@ where
     /// Note: This method is only available on platforms that support atomic
     /// operations on the given type.
     fn fetch_xor(&self, value: Self::NonAtomicType, order: Ordering) -> Self::NonAtomicType;
+
+    /// Adds to the current value, unless the result would overflow.
+    ///
+    /// Returns `Ok(previous_value)` if the value was updated, and
+    /// `Err(current_value)` if the addition would overflow, in which case the
+    /// value is left unchanged. This makes it possible to implement, e.g.,
+    /// “increment unless at limit” on reference counts.
+    ///
+    /// This is a convenience method for
+    /// [`fetch_update`](`crate::Atomic::fetch_update`), and the orderings have the
+    /// same meaning.
+    #[inline(always)]
+    fn fetch_checked_add(
+        &self,
+        value: Self::NonAtomicType,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Result<Self::NonAtomicType, Self::NonAtomicType> {
+        self.fetch_update(set_order, fetch_order, |x| x.checked_add(value))
+    }
+
+    /// Subtracts from the current value, unless the result would overflow.
+    ///
+    /// Returns `Ok(previous_value)` if the value was updated, and
+    /// `Err(current_value)` if the subtraction would overflow, in which case
+    /// the value is left unchanged.
+    ///
+    /// This is a convenience method for
+    /// [`fetch_update`](`crate::Atomic::fetch_update`), and the orderings have the
+    /// same meaning.
+    #[inline(always)]
+    fn fetch_checked_sub(
+        &self,
+        value: Self::NonAtomicType,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Result<Self::NonAtomicType, Self::NonAtomicType> {
+        self.fetch_update(set_order, fetch_order, |x| x.checked_sub(value))
+    }
+
+    /// Multiplies the current value, unless the result would overflow.
+    ///
+    /// Returns `Ok(previous_value)` if the value was updated, and
+    /// `Err(current_value)` if the multiplication would overflow, in which
+    /// case the value is left unchanged.
+    ///
+    /// This is a convenience method for
+    /// [`fetch_update`](`crate::Atomic::fetch_update`), and the orderings have the
+    /// same meaning.
+    #[inline(always)]
+    fn fetch_checked_mul(
+        &self,
+        value: Self::NonAtomicType,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Result<Self::NonAtomicType, Self::NonAtomicType> {
+        self.fetch_update(set_order, fetch_order, |x| x.checked_mul(value))
+    }
+
+    /// Multiplies the current value, returning the previous value.
+    ///
+    /// This operation wraps around on overflow.
+    ///
+    /// This is a convenience method for
+    /// [`fetch_update`](`crate::Atomic::fetch_update`), and the orderings have the
+    /// same meaning.
+    #[inline(always)]
+    fn fetch_wrapping_mul(
+        &self,
+        value: Self::NonAtomicType,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.wrapping_mul(value)).0
+    }
+
+    /// Shifts left the current value, returning the previous value.
+    ///
+    /// As in [`Integer::wrapping_shl`], the shift is performed modulo the
+    /// number of bits of the type.
+    ///
+    /// This is a convenience method for
+    /// [`fetch_update`](`crate::Atomic::fetch_update`), and the orderings have the
+    /// same meaning.
+    #[inline(always)]
+    fn fetch_wrapping_shl(
+        &self,
+        rhs: u32,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.wrapping_shl(rhs)).0
+    }
+
+    /// Shifts right the current value, returning the previous value.
+    ///
+    /// As in [`Integer::wrapping_shr`], the shift is performed modulo the
+    /// number of bits of the type, and it is arithmetic for signed types.
+    ///
+    /// This is a convenience method for
+    /// [`fetch_update`](`crate::Atomic::fetch_update`), and the orderings have the
+    /// same meaning.
+    #[inline(always)]
+    fn fetch_wrapping_shr(
+        &self,
+        rhs: u32,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.wrapping_shr(rhs)).0
+    }
+
+    /// Rotates left the current value, returning the previous value.
+    ///
+    /// This is a convenience method for
+    /// [`fetch_update`](`crate::Atomic::fetch_update`), and the orderings have the
+    /// same meaning.
+    #[inline(always)]
+    fn fetch_rotate_left(
+        &self,
+        n: u32,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.rotate_left(n)).0
+    }
+
+    /// Rotates right the current value, returning the previous value.
+    ///
+    /// This is a convenience method for
+    /// [`fetch_update`](`crate::Atomic::fetch_update`), and the orderings have the
+    /// same meaning.
+    #[inline(always)]
+    fn fetch_rotate_right(
+        &self,
+        n: u32,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Self::NonAtomicType {
+        update(self, set_order, fetch_order, |x| x.rotate_right(n)).0
+    }
 }
 
 /// An atomic signed integer type.
@@ -96,4 +238,187 @@ pub trait AtomicUnsignedInt: AtomicInteger + IsSigned<Signed = False>
 where
     Self::NonAtomicType: UnsignedInt,
 {
+    /// Adds a signed value to the current value, returning the previous value.
+    ///
+    /// This operation wraps around on overflow, and, as two's complement
+    /// addition does not depend on signedness, it is performed by
+    /// [`fetch_add`](crate::AtomicNumber::fetch_add), so the ordering has the
+    /// same meaning.
+    #[inline(always)]
+    fn fetch_add_signed(
+        &self,
+        value: <Self::NonAtomicType as UnsignedInt>::SignedInt,
+        order: Ordering,
+    ) -> Self::NonAtomicType {
+        self.fetch_add(value.to_unsigned(), order)
+    }
+
+    /// Adds a signed value to the current value, unless the result would
+    /// overflow (i.e., go below zero or above the maximum value).
+    ///
+    /// Returns `Ok(previous_value)` if the value was updated, and
+    /// `Err(current_value)` otherwise, in which case the value is left
+    /// unchanged.
+    ///
+    /// This is a convenience method for
+    /// [`fetch_update`](`crate::Atomic::fetch_update`), and the orderings have the
+    /// same meaning.
+    #[inline(always)]
+    fn fetch_checked_add_signed(
+        &self,
+        value: <Self::NonAtomicType as UnsignedInt>::SignedInt,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Result<Self::NonAtomicType, Self::NonAtomicType> {
+        self.fetch_update(set_order, fetch_order, |x| x.checked_add_signed(value))
+    }
+
+    /// Loads the bits in range [`start_bit`, `end_bit`) of the current value,
+    /// with the same conventions of [`Integer::extract_bitfield`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is [`Release`](`core::sync::atomic::Ordering::Release`)
+    /// or [`AcqRel`](`core::sync::atomic::Ordering::AcqRel`).
+    #[inline(always)]
+    fn load_bitfield(
+        &self,
+        start_bit: usize,
+        end_bit: usize,
+        order: Ordering,
+    ) -> Self::NonAtomicType {
+        self.load(order).extract_bitfield(start_bit, end_bit)
+    }
+
+    /// Stores `value` in the bits in range [`start_bit`, `end_bit`) of the
+    /// current value, leaving the other bits untouched.
+    ///
+    /// The bits of `value` beyond the width of the field are ignored (in
+    /// debug mode, they must be zero).
+    ///
+    /// This is a convenience method for
+    /// [`fetch_update`](`crate::Atomic::fetch_update`), and the orderings have the
+    /// same meaning.
+    #[inline(always)]
+    fn store_bitfield(
+        &self,
+        start_bit: usize,
+        end_bit: usize,
+        value: Self::NonAtomicType,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) {
+        let mask = bitfield_mask::<Self::NonAtomicType>(start_bit, end_bit);
+        debug_assert!(
+            value <= mask >> start_bit,
+            "the value does not fit the bitfield"
+        );
+        let bits = (value << start_bit) & mask;
+        let _ = self.fetch_update(set_order, fetch_order, |word| Some((word & !mask) | bits));
+    }
+
+    /// Adds `value` to the bits in range [`start_bit`, `end_bit`) of the
+    /// current value, seen as an unsigned integer, unless the result does not
+    /// fit the field; the other bits are left untouched.
+    ///
+    /// Returns `Ok(previous_field)` if the field was updated, and
+    /// `Err(current_field)` if the addition would overflow the field, in which
+    /// case the value is left unchanged. For example, the fields might be the
+    /// saturating counters of a counting Bloom filter.
+    ///
+    /// This is a convenience method for
+    /// [`fetch_update`](`crate::Atomic::fetch_update`), and the orderings have the
+    /// same meaning.
+    #[inline(always)]
+    fn fetch_add_bitfield(
+        &self,
+        start_bit: usize,
+        end_bit: usize,
+        value: Self::NonAtomicType,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Result<Self::NonAtomicType, Self::NonAtomicType> {
+        let mask = bitfield_mask::<Self::NonAtomicType>(start_bit, end_bit);
+        self.fetch_update(set_order, fetch_order, |word| {
+            let field = word
+                .extract_bitfield(start_bit, end_bit)
+                .checked_add(value)?;
+            (field <= mask >> start_bit).then(|| (word & !mask) | (field << start_bit))
+        })
+        .map(|word| word.extract_bitfield(start_bit, end_bit))
+        .map_err(|word| word.extract_bitfield(start_bit, end_bit))
+    }
+
+    /// Subtracts `value` from the bits in range [`start_bit`, `end_bit`) of
+    /// the current value, seen as an unsigned integer, unless the result
+    /// would be negative; the other bits are left untouched.
+    ///
+    /// Returns `Ok(previous_field)` if the field was updated, and
+    /// `Err(current_field)` otherwise, in which case the value is left
+    /// unchanged.
+    ///
+    /// This is a convenience method for
+    /// [`fetch_update`](`crate::Atomic::fetch_update`), and the orderings have the
+    /// same meaning.
+    #[inline(always)]
+    fn fetch_sub_bitfield(
+        &self,
+        start_bit: usize,
+        end_bit: usize,
+        value: Self::NonAtomicType,
+        set_order: Ordering,
+        fetch_order: Ordering,
+    ) -> Result<Self::NonAtomicType, Self::NonAtomicType> {
+        let mask = bitfield_mask::<Self::NonAtomicType>(start_bit, end_bit);
+        self.fetch_update(set_order, fetch_order, |word| {
+            let field = word
+                .extract_bitfield(start_bit, end_bit)
+                .checked_sub(value)?;
+            Some((word & !mask) | (field << start_bit))
+        })
+        .map(|word| word.extract_bitfield(start_bit, end_bit))
+        .map_err(|word| word.extract_bitfield(start_bit, end_bit))
+    }
+
+    /// Stores `new` in the bits in range [`start_bit`, `end_bit`) of the
+    /// current value if they are equal to `current`, leaving the other bits
+    /// untouched.
+    ///
+    /// Returns `Ok(current)` if the field was updated, and
+    /// `Err(current_field)` otherwise. Differently from
+    /// [`compare_exchange`](crate::Atomic::compare_exchange) on the whole value,
+    /// concurrent modifications of other bits do not cause a failure. The
+    /// bits of `new` beyond the width of the field are ignored (in debug mode,
+    /// they must be zero).
+    ///
+    /// The orderings have the same meaning as in
+    /// [`compare_exchange`](crate::Atomic::compare_exchange).
+    #[inline(always)]
+    fn compare_exchange_bitfield(
+        &self,
+        start_bit: usize,
+        end_bit: usize,
+        current: Self::NonAtomicType,
+        new: Self::NonAtomicType,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self::NonAtomicType, Self::NonAtomicType> {
+        let mask = bitfield_mask::<Self::NonAtomicType>(start_bit, end_bit);
+        debug_assert!(
+            new <= mask >> start_bit,
+            "the value does not fit the bitfield"
+        );
+        let bits = (new << start_bit) & mask;
+        self.fetch_update(success, failure, |word| {
+            (word.extract_bitfield(start_bit, end_bit) == current).then_some((word & !mask) | bits)
+        })
+        .map(|word| word.extract_bitfield(start_bit, end_bit))
+        .map_err(|word| word.extract_bitfield(start_bit, end_bit))
+    }
+}
+
+/// Returns a mask selecting the bits in range [`start_bit`, `end_bit`).
+#[inline(always)]
+fn bitfield_mask<T: UnsignedInt>(start_bit: usize, end_bit: usize) -> T {
+    (!T::ZERO).extract_bitfield(start_bit, end_bit) << start_bit
 }
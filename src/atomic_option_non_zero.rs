@@ -0,0 +1,299 @@
+#[cfg(loom)]
+use crate::sync::check_layout;
+use crate::sync::{
+    AtomicI8, AtomicI16, AtomicI32, AtomicI64, AtomicIsize, AtomicU8, AtomicU16, AtomicU32,
+    AtomicU64, AtomicUsize,
+};
+use crate::{Atomic, AtomicI128, AtomicU128, False, IntoAtomic, IsAtomic, NonZero, SameAs, True};
+use core::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+};
+use core::sync::atomic::Ordering;
+
+/// Atomic [`Option`] of a [`NonZero`] integer, based on the atomic version of
+/// the underlying primitive type, in which zero represents [`None`].
+///
+/// Since `Option<NonZeroU32>` has the same memory layout as `u32`, which in
+/// turn has the same memory layout as [`AtomicU32`], slices and arrays can be
+/// converted without copying using the methods of [`Atomic`] and
+/// [`IntoAtomic`].
+///
+/// # Examples
+///
+/// ```rust
+/// use common_traits::{Atomic, AtomicOptionNonZero, IntoAtomic};
+/// use core::num::NonZeroU32;
+/// use core::sync::atomic::Ordering;
+///
+/// let mut slots: Vec<Option<NonZeroU32>> = vec![None; 4];
+/// let atomic_slots = <Option<NonZeroU32>>::from_mut_slice(&mut slots);
+/// let one = NonZeroU32::new(1);
+/// assert_eq!(atomic_slots[2].replace(one.unwrap(), Ordering::Relaxed), None);
+/// assert_eq!(atomic_slots[2].take(Ordering::Relaxed), one);
+/// assert!(atomic_slots[2].compare_exchange(None, one, Ordering::Relaxed, Ordering::Relaxed).is_ok());
+/// assert_eq!(slots[2], one);
+/// ```
+#[repr(transparent)]
+pub struct AtomicOptionNonZero<T: NonZero>(<T::BaseType as IntoAtomic>::AtomicType)
+where
+    T::BaseType: IntoAtomic;
+
+impl<T: NonZero> IsAtomic for AtomicOptionNonZero<T>
+where
+    T::BaseType: IntoAtomic,
+{
+    type Atomic = True;
+}
+
+impl<T: NonZero> IsAtomic for Option<T> {
+    type Atomic = False;
+}
+
+impl<T: NonZero> AtomicOptionNonZero<T>
+where
+    T::BaseType: IntoAtomic,
+    Self: Atomic<NonAtomicType = Option<T>>,
+{
+    /// Takes the value out of the atomic, leaving [`None`] in its place.
+    #[inline(always)]
+    pub fn take(&self, order: Ordering) -> Option<T> {
+        self.swap(None, order)
+    }
+
+    /// Replaces the value of the atomic with `value`, returning the previous
+    /// value.
+    #[inline(always)]
+    pub fn replace(&self, value: T, order: Ordering) -> Option<T> {
+        self.swap(Some(value), order)
+    }
+}
+
+impl<T: NonZero> Default for AtomicOptionNonZero<T>
+where
+    T::BaseType: IntoAtomic,
+    Self: Atomic<NonAtomicType = Option<T>>,
+{
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl<T: NonZero + core::fmt::Debug> core::fmt::Debug for AtomicOptionNonZero<T>
+where
+    T::BaseType: IntoAtomic,
+    Self: Atomic<NonAtomicType = Option<T>>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
+
+macro_rules! impl_atomic_option_non_zero {
+    ($($nz:ty, $aty:ty;)*) => {$(
+impl IntoAtomic for Option<$nz> {
+    type AtomicType = AtomicOptionNonZero<$nz>;
+
+    #[inline(always)]
+    fn to_atomic(self) -> Self::AtomicType {
+        Self::AtomicType::new(self)
+    }
+
+    #[inline(always)]
+    fn into_atomic_array<const N: usize>(data: [Self; N]) -> [Self::AtomicType; N] {
+        core::array::from_fn(|i| Self::AtomicType::new(data[i]))
+    }
+
+    #[inline(always)]
+    fn from_atomic_array<const N: usize>(data: [Self::AtomicType; N]) -> [Self; N] {
+        #[cfg(not(loom))]
+        unsafe { *(data.as_ptr() as *const [Self; N]) }
+        #[cfg(loom)]
+        {
+            data.map(Self::AtomicType::into_inner)
+        }
+    }
+
+    #[inline(always)]
+    fn get_mut_slice(this: &mut [Self::AtomicType]) -> &mut [Self] {
+        #[cfg(loom)]
+        check_layout();
+        unsafe { core::mem::transmute::<&mut [Self::AtomicType], &mut [Self]>(this) }
+    }
+
+    #[inline(always)]
+    fn from_mut_slice(this: &mut [Self]) -> &mut [Self::AtomicType] {
+        #[cfg(loom)]
+        check_layout();
+        unsafe { core::mem::transmute::<&mut [Self], &mut [Self::AtomicType]>(this) }
+    }
+
+    #[inline(always)]
+    fn get_mut_array<const N: usize>(this: &mut [Self::AtomicType; N]) -> &mut [Self; N] {
+        #[cfg(loom)]
+        check_layout();
+        unsafe { core::mem::transmute::<&mut [Self::AtomicType; N], &mut [Self; N]>(this) }
+    }
+
+    #[inline(always)]
+    fn from_mut_array<const N: usize>(this: &mut [Self; N]) -> &mut [Self::AtomicType; N] {
+        #[cfg(loom)]
+        check_layout();
+        unsafe { core::mem::transmute::<&mut [Self; N], &mut [Self::AtomicType; N]>(this) }
+    }
+}
+
+unsafe impl SameAs<AtomicOptionNonZero<$nz>> for Option<$nz> {}
+
+impl Atomic for AtomicOptionNonZero<$nz> {
+    type NonAtomicType = Option<$nz>;
+
+    #[inline(always)]
+    fn new(value: Self::NonAtomicType) -> Self {
+        Self(<$aty>::new(value.map_or(0, <$nz>::get)))
+    }
+
+    #[inline(always)]
+    fn load(&self, order: Ordering) -> Self::NonAtomicType {
+        <$nz>::new(self.0.load(order))
+    }
+
+    #[inline(always)]
+    fn store(&self, value: Self::NonAtomicType, order: Ordering) {
+        self.0.store(value.map_or(0, <$nz>::get), order)
+    }
+
+    #[inline(always)]
+    fn get_mut(&mut self) -> &mut Self::NonAtomicType {
+        #[cfg(loom)]
+        check_layout();
+        unsafe { &mut *(self as *mut Self as *mut Self::NonAtomicType) }
+    }
+
+    #[inline(always)]
+    fn into_inner(self) -> Self::NonAtomicType {
+        <$nz>::new(self.0.into_inner())
+    }
+
+    #[inline(always)]
+    fn into_non_atomic_array<const N: usize>(data: [Self; N]) -> [Self::NonAtomicType; N] {
+        #[cfg(not(loom))]
+        unsafe { *(data.as_ptr() as *const [Self::NonAtomicType; N]) }
+        #[cfg(loom)]
+        {
+            data.map(Self::into_inner)
+        }
+    }
+
+    #[inline(always)]
+    fn from_non_atomic_array<const N: usize>(data: [Self::NonAtomicType; N]) -> [Self; N] {
+        core::array::from_fn(|i| Self::new(data[i]))
+    }
+
+    #[inline(always)]
+    fn get_mut_slice(this: &mut [Self]) -> &mut [Self::NonAtomicType] {
+        #[cfg(loom)]
+        check_layout();
+        unsafe { core::mem::transmute::<&mut [Self], &mut [Self::NonAtomicType]>(this) }
+    }
+
+    #[inline(always)]
+    fn from_mut_slice(this: &mut [Self::NonAtomicType]) -> &mut [Self] {
+        #[cfg(loom)]
+        check_layout();
+        unsafe { core::mem::transmute::<&mut [Self::NonAtomicType], &mut [Self]>(this) }
+    }
+
+    #[inline(always)]
+    fn get_mut_array<const N: usize>(this: &mut [Self; N]) -> &mut [Self::NonAtomicType; N] {
+        #[cfg(loom)]
+        check_layout();
+        unsafe { core::mem::transmute::<&mut [Self; N], &mut [Self::NonAtomicType; N]>(this) }
+    }
+
+    #[inline(always)]
+    fn from_mut_array<const N: usize>(this: &mut [Self::NonAtomicType; N]) -> &mut [Self; N] {
+        #[cfg(loom)]
+        check_layout();
+        unsafe { core::mem::transmute::<&mut [Self::NonAtomicType; N], &mut [Self; N]>(this) }
+    }
+
+    #[inline(always)]
+    fn compare_exchange(
+        &self,
+        current: Self::NonAtomicType,
+        new: Self::NonAtomicType,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self::NonAtomicType, Self::NonAtomicType> {
+        self.0
+            .compare_exchange(
+                current.map_or(0, <$nz>::get),
+                new.map_or(0, <$nz>::get),
+                success,
+                failure,
+            )
+            .map(<$nz>::new)
+            .map_err(<$nz>::new)
+    }
+
+    #[inline(always)]
+    fn compare_exchange_weak(
+        &self,
+        current: Self::NonAtomicType,
+        new: Self::NonAtomicType,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self::NonAtomicType, Self::NonAtomicType> {
+        self.0
+            .compare_exchange_weak(
+                current.map_or(0, <$nz>::get),
+                new.map_or(0, <$nz>::get),
+                success,
+                failure,
+            )
+            .map(<$nz>::new)
+            .map_err(<$nz>::new)
+    }
+
+    #[inline(always)]
+    fn swap(&self, new: Self::NonAtomicType, order: Ordering) -> Self::NonAtomicType {
+        <$nz>::new(self.0.swap(new.map_or(0, <$nz>::get), order))
+    }
+
+    #[inline(always)]
+    fn fetch_update<F>(
+        &self,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        mut f: F,
+    ) -> Result<Self::NonAtomicType, Self::NonAtomicType>
+    where
+        F: FnMut(Self::NonAtomicType) -> Option<Self::NonAtomicType>,
+    {
+        self.0
+            .fetch_update(set_order, fetch_order, |x| {
+                f(<$nz>::new(x)).map(|x| x.map_or(0, <$nz>::get))
+            })
+            .map(<$nz>::new)
+            .map_err(<$nz>::new)
+    }
+}
+    )*};
+}
+
+impl_atomic_option_non_zero!(
+    NonZeroU8, AtomicU8;
+    NonZeroU16, AtomicU16;
+    NonZeroU32, AtomicU32;
+    NonZeroU64, AtomicU64;
+    NonZeroU128, AtomicU128;
+    NonZeroUsize, AtomicUsize;
+    NonZeroI8, AtomicI8;
+    NonZeroI16, AtomicI16;
+    NonZeroI32, AtomicI32;
+    NonZeroI64, AtomicI64;
+    NonZeroI128, AtomicI128;
+    NonZeroIsize, AtomicIsize;
+);
@@ -0,0 +1,383 @@
+use crate::atomic::load_ordering;
+use crate::sync::{AtomicU16, AtomicU32, AtomicU64};
+use crate::{Atomic, AtomicU128};
+use core::sync::atomic::Ordering;
+
+mod private {
+    use crate::Atomic;
+
+    /// Sealed trait for the types that can be a component of an
+    /// [`AtomicPair`](super::AtomicPair).
+    pub trait PairComponent: Copy {
+        /// The number of bits of the type.
+        const BITS: u32;
+        /// Returns the bits of `self` in the lowest [`BITS`](Self::BITS)
+        /// bits, the remaining ones being zero.
+        fn into_raw(self) -> u64;
+        /// Inverse of [`into_raw`](Self::into_raw), ignoring the bits above
+        /// [`BITS`](Self::BITS).
+        fn from_raw(raw: u64) -> Self;
+        /// Adds `rhs`, wrapping around on overflow for integers.
+        fn wrapping_add(self, rhs: Self) -> Self;
+    }
+
+    /// Sealed trait for the pairs that can be packed in an atomic unsigned
+    /// integer.
+    pub trait PairStorage: Sized {
+        type Atomic: Atomic;
+        fn pack(self) -> <Self::Atomic as Atomic>::NonAtomicType;
+        fn unpack(int: <Self::Atomic as Atomic>::NonAtomicType) -> Self;
+    }
+}
+
+pub(crate) use private::{PairComponent, PairStorage};
+
+macro_rules! impl_pair_component_int {
+    ($($ty:ty, $uty:ty;)*) => {$(
+impl PairComponent for $ty {
+    const BITS: u32 = <$ty>::BITS;
+
+    #[inline(always)]
+    fn into_raw(self) -> u64 {
+        self as $uty as u64
+    }
+
+    #[inline(always)]
+    fn from_raw(raw: u64) -> Self {
+        raw as $uty as $ty
+    }
+
+    #[inline(always)]
+    fn wrapping_add(self, rhs: Self) -> Self {
+        <$ty>::wrapping_add(self, rhs)
+    }
+}
+    )*};
+}
+
+impl_pair_component_int!(
+    u8, u8;
+    u16, u16;
+    u32, u32;
+    u64, u64;
+    i8, u8;
+    i16, u16;
+    i32, u32;
+    i64, u64;
+);
+
+macro_rules! impl_pair_component_float {
+    ($($ty:ty, $uty:ty;)*) => {$(
+impl PairComponent for $ty {
+    const BITS: u32 = <$uty>::BITS;
+
+    #[inline(always)]
+    fn into_raw(self) -> u64 {
+        self.to_bits() as u64
+    }
+
+    #[inline(always)]
+    fn from_raw(raw: u64) -> Self {
+        <$ty>::from_bits(raw as $uty)
+    }
+
+    #[inline(always)]
+    fn wrapping_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+}
+    )*};
+}
+
+impl_pair_component_float!(
+    f32, u32;
+    f64, u64;
+);
+
+macro_rules! impl_pair_storage {
+    ($int:ty, $aty:ty; [$($a:ty),*] x $bs:tt) => {$(
+        impl_pair_storage!(@inner $int, $aty; $a, $bs);
+    )*};
+    (@inner $int:ty, $aty:ty; $a:ty, [$($b:ty),*]) => {$(
+impl PairStorage for ($a, $b) {
+    type Atomic = $aty;
+
+    #[inline(always)]
+    fn pack(self) -> $int {
+        self.0.into_raw() as $int | (self.1.into_raw() as $int) << <$a as PairComponent>::BITS
+    }
+
+    #[inline(always)]
+    fn unpack(int: $int) -> Self {
+        (
+            <$a>::from_raw(int as u64),
+            <$b>::from_raw((int >> <$a as PairComponent>::BITS) as u64),
+        )
+    }
+}
+    )*};
+}
+
+impl_pair_storage!(u16, AtomicU16; [u8, i8] x [u8, i8]);
+impl_pair_storage!(u32, AtomicU32; [u8, i8] x [u16, i16]);
+impl_pair_storage!(u32, AtomicU32; [u16, i16] x [u8, i8]);
+impl_pair_storage!(u32, AtomicU32; [u16, i16] x [u16, i16]);
+impl_pair_storage!(u64, AtomicU64; [u8, i8] x [u32, i32, f32]);
+impl_pair_storage!(u64, AtomicU64; [u32, i32, f32] x [u8, i8]);
+impl_pair_storage!(u64, AtomicU64; [u16, i16] x [u32, i32, f32]);
+impl_pair_storage!(u64, AtomicU64; [u32, i32, f32] x [u16, i16]);
+impl_pair_storage!(u64, AtomicU64; [u32, i32, f32] x [u32, i32, f32]);
+impl_pair_storage!(u128, AtomicU128; [u8, i8] x [u64, i64, f64]);
+impl_pair_storage!(u128, AtomicU128; [u64, i64, f64] x [u8, i8]);
+impl_pair_storage!(u128, AtomicU128; [u16, i16] x [u64, i64, f64]);
+impl_pair_storage!(u128, AtomicU128; [u64, i64, f64] x [u16, i16]);
+impl_pair_storage!(u128, AtomicU128; [u32, i32, f32] x [u64, i64, f64]);
+impl_pair_storage!(u128, AtomicU128; [u64, i64, f64] x [u32, i32, f32]);
+impl_pair_storage!(u128, AtomicU128; [u64, i64, f64] x [u64, i64, f64]);
+
+#[cfg(feature = "half")]
+mod half_impl {
+    use super::*;
+    use half::{bf16, f16};
+
+    impl_pair_component_float!(
+        f16, u16;
+        bf16, u16;
+    );
+
+    impl_pair_storage!(u32, AtomicU32; [f16, bf16] x [u8, i8, u16, i16, f16, bf16]);
+    impl_pair_storage!(u32, AtomicU32; [u8, i8, u16, i16] x [f16, bf16]);
+    impl_pair_storage!(u64, AtomicU64; [f16, bf16] x [u32, i32, f32]);
+    impl_pair_storage!(u64, AtomicU64; [u32, i32, f32] x [f16, bf16]);
+    impl_pair_storage!(u128, AtomicU128; [f16, bf16] x [u64, i64, f64]);
+    impl_pair_storage!(u128, AtomicU128; [u64, i64, f64] x [f16, bf16]);
+}
+
+/// A pair of primitive numbers packed in a single atomic unsigned integer.
+///
+/// The components can be any pair of 8, 16, 32 or 64-bit integers and
+/// floats (and of the floats of the [`half`](https://crates.io/crates/half)
+/// crate if the corresponding feature is enabled). The pair is stored in the
+/// smallest among [`AtomicU16`], [`AtomicU32`], [`AtomicU64`] and
+/// [`AtomicU128`] that can contain it, with the first component in the
+/// lowest bits.
+///
+/// Besides the usual atomic operations on the whole pair, the methods
+/// [`fetch_add_first`](AtomicPair::fetch_add_first) and
+/// [`fetch_add_second`](AtomicPair::fetch_add_second) add to a single
+/// component, and [`compare_exchange_versioned`](AtomicPair::compare_exchange_versioned)
+/// implements the ABA-prevention pattern in which the second component is a
+/// version counter incremented at each update.
+///
+/// # Examples
+///
+/// ```rust
+/// use common_traits::AtomicPair;
+/// use core::sync::atomic::Ordering;
+///
+/// // An index and its generation, packed in an AtomicU64
+/// let head = AtomicPair::new((3_u32, 0_u32));
+/// let current = head.load(Ordering::Acquire);
+/// assert_eq!(
+///     head.compare_exchange_versioned(current, 5, Ordering::AcqRel, Ordering::Acquire),
+///     Ok((3, 0))
+/// );
+/// // A stale CAS fails even if the index is the same
+/// head.compare_exchange_versioned((5, 1), 3, Ordering::AcqRel, Ordering::Acquire).unwrap();
+/// assert!(head
+///     .compare_exchange_versioned(current, 7, Ordering::AcqRel, Ordering::Acquire)
+///     .is_err());
+/// assert_eq!(head.load(Ordering::Relaxed), (3, 2));
+/// ```
+#[repr(transparent)]
+pub struct AtomicPair<A, B>(<(A, B) as PairStorage>::Atomic)
+where
+    (A, B): PairStorage;
+
+impl<A: PairComponent, B: PairComponent> AtomicPair<A, B>
+where
+    (A, B): PairStorage,
+{
+    /// Creates a new atomic pair.
+    #[inline(always)]
+    pub fn new(value: (A, B)) -> Self {
+        Self(<(A, B) as PairStorage>::Atomic::new(value.pack()))
+    }
+
+    /// Consumes the atomic and returns the contained pair.
+    #[inline(always)]
+    pub fn into_inner(self) -> (A, B) {
+        <(A, B)>::unpack(self.0.into_inner())
+    }
+
+    /// Loads the pair.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is [`Release`](Ordering::Release) or
+    /// [`AcqRel`](Ordering::AcqRel).
+    #[inline(always)]
+    pub fn load(&self, order: Ordering) -> (A, B) {
+        <(A, B)>::unpack(self.0.load(order))
+    }
+
+    /// Stores a pair.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is [`Acquire`](Ordering::Acquire) or
+    /// [`AcqRel`](Ordering::AcqRel).
+    #[inline(always)]
+    pub fn store(&self, value: (A, B), order: Ordering) {
+        self.0.store(value.pack(), order)
+    }
+
+    /// Stores a pair, returning the previous one.
+    #[inline(always)]
+    pub fn swap(&self, value: (A, B), order: Ordering) -> (A, B) {
+        <(A, B)>::unpack(self.0.swap(value.pack(), order))
+    }
+
+    /// Stores `new` if the current pair is bitwise equal to `current`.
+    ///
+    /// The return value is a result indicating whether the new pair was
+    /// written and containing the previous pair. The orderings have the same
+    /// meaning as in [`Atomic::compare_exchange`].
+    #[inline(always)]
+    pub fn compare_exchange(
+        &self,
+        current: (A, B),
+        new: (A, B),
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<(A, B), (A, B)> {
+        self.0
+            .compare_exchange(current.pack(), new.pack(), success, failure)
+            .map(<(A, B)>::unpack)
+            .map_err(<(A, B)>::unpack)
+    }
+
+    /// Same as [`compare_exchange`](Self::compare_exchange), but it is
+    /// allowed to fail spuriously.
+    #[inline(always)]
+    pub fn compare_exchange_weak(
+        &self,
+        current: (A, B),
+        new: (A, B),
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<(A, B), (A, B)> {
+        self.0
+            .compare_exchange_weak(current.pack(), new.pack(), success, failure)
+            .map(<(A, B)>::unpack)
+            .map_err(<(A, B)>::unpack)
+    }
+
+    /// Fetches the pair, and applies a function to it that returns an
+    /// optional new pair. Returns `Ok(previous_pair)` if the function
+    /// returned `Some(_)`, else `Err(previous_pair)`.
+    ///
+    /// The orderings have the same meaning as in [`Atomic::fetch_update`].
+    #[inline(always)]
+    pub fn fetch_update<F>(
+        &self,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        mut f: F,
+    ) -> Result<(A, B), (A, B)>
+    where
+        F: FnMut((A, B)) -> Option<(A, B)>,
+    {
+        self.0
+            .fetch_update(set_order, fetch_order, |x| {
+                f(<(A, B)>::unpack(x)).map(PairStorage::pack)
+            })
+            .map(<(A, B)>::unpack)
+            .map_err(<(A, B)>::unpack)
+    }
+
+    /// Adds `value` to the first component, wrapping around on overflow for
+    /// integers, and returns the previous pair.
+    #[inline(always)]
+    pub fn fetch_add_first(&self, value: A, order: Ordering) -> (A, B) {
+        // The closure always returns Some, so the result is always Ok
+        let (Ok(prev) | Err(prev)) = self.fetch_update(order, load_ordering(order), |(a, b)| {
+            Some((a.wrapping_add(value), b))
+        });
+        prev
+    }
+
+    /// Adds `value` to the second component, wrapping around on overflow for
+    /// integers, and returns the previous pair.
+    #[inline(always)]
+    pub fn fetch_add_second(&self, value: B, order: Ordering) -> (A, B) {
+        // The closure always returns Some, so the result is always Ok
+        let (Ok(prev) | Err(prev)) = self.fetch_update(order, load_ordering(order), |(a, b)| {
+            Some((a, b.wrapping_add(value)))
+        });
+        prev
+    }
+}
+
+impl<A: PairComponent, B: PairComponent + crate::Integer> AtomicPair<A, B>
+where
+    (A, B): PairStorage,
+{
+    /// Stores `new` as first component and the successor of the second
+    /// component of `current`, which is thus used as a version counter, if
+    /// the current pair is equal to `current`.
+    ///
+    /// Since the version changes at each update (wrapping around on
+    /// overflow), a compare-and-swap based on an outdated pair fails even if
+    /// the first component has been restored to the old value in the
+    /// meantime (the ABA problem).
+    ///
+    /// The return value is the same as that of
+    /// [`compare_exchange`](Self::compare_exchange).
+    #[inline(always)]
+    pub fn compare_exchange_versioned(
+        &self,
+        current: (A, B),
+        new: A,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<(A, B), (A, B)> {
+        self.compare_exchange(
+            current,
+            (new, PairComponent::wrapping_add(current.1, B::ONE)),
+            success,
+            failure,
+        )
+    }
+}
+
+impl<A: PairComponent + Default, B: PairComponent + Default> Default for AtomicPair<A, B>
+where
+    (A, B): PairStorage,
+{
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new((A::default(), B::default()))
+    }
+}
+
+impl<A, B> core::fmt::Debug for AtomicPair<A, B>
+where
+    A: PairComponent + core::fmt::Debug,
+    B: PairComponent + core::fmt::Debug,
+    (A, B): PairStorage,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
+
+impl<A: PairComponent, B: PairComponent> From<(A, B)> for AtomicPair<A, B>
+where
+    (A, B): PairStorage,
+{
+    #[inline(always)]
+    fn from(value: (A, B)) -> Self {
+        Self::new(value)
+    }
+}
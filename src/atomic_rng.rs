@@ -0,0 +1,200 @@
+use crate::sync::AtomicU64;
+use crate::{Rng, RngNext};
+use core::sync::atomic::Ordering;
+
+/// The increment of the SplitMix64 state, that is, the odd integer closest
+/// to 2⁶⁴/φ.
+const GOLDEN_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// The SplitMix64 output function.
+#[inline(always)]
+pub(crate) fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// A SplitMix64 pseudorandom number generator.
+///
+/// This is a fast generator with 64 bits of state, suitable for
+/// [seeding](AtomicRng::fork) thread-local generators from an [`AtomicRng`].
+///
+/// # Examples
+///
+/// ```rust
+/// use common_traits::{Rng, SplitMix64};
+///
+/// let mut rng = SplitMix64::new(0);
+/// let x: u64 = rng.next();
+/// let f: f64 = rng.next();
+/// assert!((0.0..1.0).contains(&f));
+/// assert_ne!(x, rng.next::<u64>());
+/// ```
+#[derive(Debug, Clone)]
+pub struct SplitMix64(u64);
+
+impl SplitMix64 {
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(GOLDEN_GAMMA);
+        mix(self.0)
+    }
+}
+
+impl Rng for SplitMix64 {
+    type Seed = u64;
+
+    #[inline(always)]
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+}
+
+/// A lock-free pseudorandom number generator that can be shared among
+/// threads.
+///
+/// The generator is a SplitMix64 generator whose state is an [`AtomicU64`]
+/// advanced by [`fetch_add`](AtomicU64::fetch_add): as the output function
+/// is a bijection, concurrent calls always return distinct 64-bit values.
+///
+/// [`RngNext`] is implemented both for `AtomicRng` and for `&AtomicRng`, so
+/// a shared reference can be passed wherever a mutable reference to a
+/// generator is expected. For the same reason, [`next`](AtomicRng::next)
+/// takes `&self`.
+///
+/// Every call performs an atomic read-modify-write operation on the same
+/// cache line, so threads generating many values should rather
+/// [`fork`](AtomicRng::fork) a local generator.
+///
+/// # Examples
+///
+/// ```rust
+/// use common_traits::{AtomicRng, Rng, SplitMix64};
+///
+/// let rng = AtomicRng::new(42);
+/// std::thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| {
+///             let x: u32 = rng.next();
+///             let mut local: SplitMix64 = rng.fork();
+///             let y: f64 = local.next();
+///             assert!((0.0..1.0).contains(&y));
+///         });
+///     }
+/// });
+/// ```
+#[derive(Debug, Default)]
+pub struct AtomicRng(AtomicU64);
+
+impl AtomicRng {
+    /// Returns the next random value of type `T`.
+    #[inline(always)]
+    pub fn next<T>(&self) -> T
+    where
+        for<'a> &'a Self: RngNext<T>,
+    {
+        let mut this = self;
+        <&Self as RngNext<T>>::next_inner(&mut this)
+    }
+
+    /// Returns a new local generator seeded from this generator.
+    ///
+    /// The local generator does not need atomic operations, and it is thus
+    /// much faster.
+    #[inline(always)]
+    pub fn fork<R: Rng>(&self) -> R
+    where
+        for<'a> &'a Self: RngNext<R::Seed>,
+    {
+        R::new(self.next())
+    }
+
+    #[inline(always)]
+    fn next_u64(&self) -> u64 {
+        mix(self
+            .0
+            .fetch_add(GOLDEN_GAMMA, Ordering::Relaxed)
+            .wrapping_add(GOLDEN_GAMMA))
+    }
+}
+
+impl Rng for AtomicRng {
+    type Seed = u64;
+
+    #[inline(always)]
+    fn new(seed: u64) -> Self {
+        Self(AtomicU64::new(seed))
+    }
+}
+
+macro_rules! impl_rng_next {
+    ($rng:ty; $($ty:ty),*; $($sty:ty => $uty:ty),*) => {
+        impl RngNext<u64> for $rng {
+            #[inline(always)]
+            fn next_inner(&mut self) -> u64 {
+                self.next_u64()
+            }
+        }
+
+        impl RngNext<u128> for $rng {
+            #[inline(always)]
+            fn next_inner(&mut self) -> u128 {
+                ((self.next_u64() as u128) << 64) | self.next_u64() as u128
+            }
+        }
+
+        $(
+        impl RngNext<$ty> for $rng {
+            #[inline(always)]
+            fn next_inner(&mut self) -> $ty {
+                // the upper bits are the best ones
+                (self.next_u64() >> (64 - <$ty>::BITS)) as $ty
+            }
+        }
+        )*
+
+        $(
+        impl RngNext<$sty> for $rng {
+            #[inline(always)]
+            fn next_inner(&mut self) -> $sty {
+                <Self as RngNext<$uty>>::next_inner(self) as $sty
+            }
+        }
+        )*
+
+        impl RngNext<bool> for $rng {
+            #[inline(always)]
+            fn next_inner(&mut self) -> bool {
+                (self.next_u64() as i64) < 0
+            }
+        }
+
+        impl RngNext<f64> for $rng {
+            /// Returns a value uniformly distributed in [0 . . 1).
+            #[inline(always)]
+            fn next_inner(&mut self) -> f64 {
+                (self.next_u64() >> 11) as f64 * (1.0 / (1_u64 << 53) as f64)
+            }
+        }
+
+        impl RngNext<f32> for $rng {
+            /// Returns a value uniformly distributed in [0 . . 1).
+            #[inline(always)]
+            fn next_inner(&mut self) -> f32 {
+                (self.next_u64() >> 40) as f32 * (1.0 / (1_u32 << 24) as f32)
+            }
+        }
+    };
+}
+
+macro_rules! impl_rng_next_all {
+    ($($rng:ty),*) => {$(
+        impl_rng_next!(
+            $rng;
+            u8, u16, u32, usize;
+            i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize
+        );
+    )*};
+}
+
+impl_rng_next_all!(SplitMix64, AtomicRng, &AtomicRng);
@@ -0,0 +1,325 @@
+use crate::{Atomic, AtomicNumber, Number, Sequence};
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use anyhow::{Result, bail};
+use core::sync::atomic::Ordering;
+
+/// A trait for types that can be viewed as a sequence of atomic elements,
+/// such as `&[AtomicUsize]`.
+///
+/// Differently from [`SequenceMut`](crate::SequenceMut), elements can be
+/// modified through a shared reference, so many threads can write to the same
+/// sequence concurrently. All methods take the
+/// [`Ordering`](`core::sync::atomic::Ordering`) of the atomic operation they
+/// perform, with the same meaning as in the methods of [`Atomic`].
+///
+/// The adapter [`WithOrdering`] makes it possible to use an atomic sequence
+/// as a [`Sequence`] loading the elements with a fixed ordering.
+///
+/// # Examples
+///
+/// ```rust
+/// use common_traits::{AtomicSequence, Sequence, WithOrdering};
+/// use core::sync::atomic::{AtomicUsize, Ordering};
+///
+/// let degrees: Vec<AtomicUsize> = (0..3).map(|_| AtomicUsize::new(0)).collect();
+/// std::thread::scope(|s| {
+///     for _ in 0..2 {
+///         s.spawn(|| {
+///             for node in 0..3 {
+///                 degrees.fetch_add_at(node, node, Ordering::Relaxed).unwrap();
+///             }
+///         });
+///     }
+/// });
+/// let view = WithOrdering::new(&degrees, Ordering::Relaxed);
+/// assert_eq!(view.iter().collect::<Vec<_>>(), vec![0, 2, 4]);
+/// ```
+#[impl_tools::autoimpl(for<T: trait + ?Sized> &T, &mut T)]
+#[cfg_attr(
+    any(feature = "alloc", feature = "std"),
+    impl_tools::autoimpl(for<T: trait + ?Sized> Box<T>)
+)]
+pub trait AtomicSequence {
+    /// The type of the atomic elements stored in the sequence.
+    type Item: Atomic;
+
+    /// Returns the length of the sequence.
+    fn len(&self) -> usize;
+
+    /// Returns a reference to the atomic element of the sequence at the given
+    /// position, without doing any bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Must not be called with `index` out of the sequence bounds.
+    unsafe fn get_atomic_unchecked(&self, index: usize) -> &Self::Item;
+
+    /// Returns whether the sequence has length zero.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the atomic element of the sequence at the given
+    /// position, or an error if the position is out of bounds.
+    fn get_atomic(&self, index: usize) -> Result<&Self::Item> {
+        if index >= self.len() {
+            bail!(
+                "The index {} is out of bounds for the AtomicSequence of length {}",
+                index,
+                self.len(),
+            );
+        }
+        Ok(unsafe { self.get_atomic_unchecked(index) })
+    }
+
+    /// Loads the element of the sequence at the given position, without
+    /// doing any bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Must not be called with `index` out of the sequence bounds.
+    #[inline(always)]
+    unsafe fn get_unchecked(
+        &self,
+        index: usize,
+        order: Ordering,
+    ) -> <Self::Item as Atomic>::NonAtomicType {
+        unsafe { self.get_atomic_unchecked(index) }.load(order)
+    }
+
+    /// Loads the element of the sequence at the given position, or returns an
+    /// error if the position is out of bounds.
+    #[inline(always)]
+    fn get(&self, index: usize, order: Ordering) -> Result<<Self::Item as Atomic>::NonAtomicType> {
+        Ok(self.get_atomic(index)?.load(order))
+    }
+
+    /// Stores a value in the element of the sequence at the given position,
+    /// without doing any bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// Must not be called with `index` out of the sequence bounds.
+    #[inline(always)]
+    unsafe fn set_unchecked(
+        &self,
+        index: usize,
+        value: <Self::Item as Atomic>::NonAtomicType,
+        order: Ordering,
+    ) {
+        unsafe { self.get_atomic_unchecked(index) }.store(value, order)
+    }
+
+    /// Stores a value in the element of the sequence at the given position,
+    /// or returns an error if the position is out of bounds.
+    #[inline(always)]
+    fn set(
+        &self,
+        index: usize,
+        value: <Self::Item as Atomic>::NonAtomicType,
+        order: Ordering,
+    ) -> Result<()> {
+        self.get_atomic(index)?.store(value, order);
+        Ok(())
+    }
+
+    /// Adds `value` to the element of the sequence at the given position,
+    /// returning the previous value, or returns an error if the position is
+    /// out of bounds.
+    #[inline(always)]
+    fn fetch_add_at(
+        &self,
+        index: usize,
+        value: <Self::Item as Atomic>::NonAtomicType,
+        order: Ordering,
+    ) -> Result<<Self::Item as Atomic>::NonAtomicType>
+    where
+        Self::Item: AtomicNumber,
+        <Self::Item as Atomic>::NonAtomicType: Number,
+    {
+        Ok(self.get_atomic(index)?.fetch_add(value, order))
+    }
+
+    /// Stores `new` in the element of the sequence at the given position if
+    /// its current value is equal to `current`, or returns an error if the
+    /// position is out of bounds.
+    ///
+    /// The inner result is the result of [`Atomic::compare_exchange`].
+    #[inline(always)]
+    #[allow(clippy::type_complexity)]
+    fn compare_exchange_at(
+        &self,
+        index: usize,
+        current: <Self::Item as Atomic>::NonAtomicType,
+        new: <Self::Item as Atomic>::NonAtomicType,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<
+        core::result::Result<
+            <Self::Item as Atomic>::NonAtomicType,
+            <Self::Item as Atomic>::NonAtomicType,
+        >,
+    > {
+        Ok(self
+            .get_atomic(index)?
+            .compare_exchange(current, new, success, failure))
+    }
+}
+
+impl<A: Atomic, const N: usize> AtomicSequence for [A; N] {
+    type Item = A;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        N
+    }
+
+    #[inline(always)]
+    unsafe fn get_atomic_unchecked(&self, index: usize) -> &A {
+        unsafe {
+            debug_assert!(index < N, "{} {}", index, N);
+            <[A]>::get_unchecked(self, index)
+        }
+    }
+}
+
+impl<A: Atomic> AtomicSequence for [A] {
+    type Item = A;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        <[A]>::len(self)
+    }
+
+    #[inline(always)]
+    unsafe fn get_atomic_unchecked(&self, index: usize) -> &A {
+        unsafe {
+            debug_assert!(index < self.len(), "{} {}", index, self.len());
+            <[A]>::get_unchecked(self, index)
+        }
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<A: Atomic> AtomicSequence for Vec<A> {
+    type Item = A;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        <Vec<A>>::len(self)
+    }
+
+    #[inline(always)]
+    unsafe fn get_atomic_unchecked(&self, index: usize) -> &A {
+        unsafe {
+            debug_assert!(index < self.len(), "{} {}", index, self.len());
+            <[A]>::get_unchecked(self, index)
+        }
+    }
+}
+
+/// A [`Sequence`] view of an [`AtomicSequence`] loading the elements with a
+/// fixed ordering.
+///
+/// The view usually wraps a reference, so that the same storage can be
+/// handed to code reading a [`Sequence`] after being filled concurrently.
+///
+/// # Panics
+///
+/// Loading elements panics if the ordering is
+/// [`Release`](`core::sync::atomic::Ordering::Release`) or
+/// [`AcqRel`](`core::sync::atomic::Ordering::AcqRel`).
+#[derive(Debug, Clone, Copy)]
+pub struct WithOrdering<S> {
+    sequence: S,
+    order: Ordering,
+}
+
+impl<S: AtomicSequence> WithOrdering<S> {
+    /// Creates a view of `sequence` loading the elements with ordering
+    /// `order`.
+    #[inline(always)]
+    pub fn new(sequence: S, order: Ordering) -> Self {
+        Self { sequence, order }
+    }
+
+    /// Returns the ordering used to load the elements.
+    #[inline(always)]
+    pub fn order(&self) -> Ordering {
+        self.order
+    }
+
+    /// Returns the underlying atomic sequence.
+    #[inline(always)]
+    pub fn into_inner(self) -> S {
+        self.sequence
+    }
+}
+
+impl<S: AtomicSequence> Sequence for WithOrdering<S>
+where
+    <S::Item as Atomic>::NonAtomicType: Copy,
+{
+    type Item = <S::Item as Atomic>::NonAtomicType;
+    type Iter<'a>
+        = WithOrderingIter<'a, S>
+    where
+        Self::Item: 'a,
+        Self: 'a;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.sequence.len()
+    }
+
+    #[inline(always)]
+    unsafe fn get_unchecked(&self, index: usize) -> Self::Item {
+        unsafe { self.sequence.get_unchecked(index, self.order) }
+    }
+
+    #[inline(always)]
+    fn iter(&self) -> Self::Iter<'_> {
+        WithOrderingIter {
+            view: self,
+            index: 0,
+        }
+    }
+}
+
+/// The iterator returned by [`WithOrdering::iter`](Sequence::iter).
+#[derive(Debug)]
+pub struct WithOrderingIter<'a, S> {
+    view: &'a WithOrdering<S>,
+    index: usize,
+}
+
+impl<S: AtomicSequence> Iterator for WithOrderingIter<'_, S>
+where
+    <S::Item as Atomic>::NonAtomicType: Copy,
+{
+    type Item = <S::Item as Atomic>::NonAtomicType;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.view.len() {
+            return None;
+        }
+        let value = unsafe { self.view.get_unchecked(self.index) };
+        self.index += 1;
+        Some(value)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.view.len() - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<S: AtomicSequence> ExactSizeIterator for WithOrderingIter<'_, S> where
+    <S::Item as Atomic>::NonAtomicType: Copy
+{
+}
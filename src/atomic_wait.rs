@@ -0,0 +1,247 @@
+use crate::sync::{AtomicI32, AtomicU32};
+use crate::{AsBytes, Atomic, AtomicCell, AtomicF32, AtomicOptionNonZero};
+use core::num::{NonZeroI32, NonZeroU32};
+#[cfg(not(loom))]
+use core::sync::atomic::Ordering;
+use core::time::Duration;
+
+/// Number of times [`AtomicWait::wait`] checks the value before blocking.
+///
+/// Under `cfg(loom)` there is no spinning, as every check would
+/// multiply the number of executions to explore.
+#[cfg(not(loom))]
+const SPIN_LIMIT: usize = 100;
+
+/// Wait/notify operations on 4-byte atomics, in the style of Linux futexes.
+///
+/// The trait makes it possible to block a thread until an atomic changes
+/// value without pairing the atomic with a
+/// [`Condvar`](std::sync::Condvar): a thread calls
+/// [`wait`](AtomicWait::wait) with the value it expects, and a thread
+/// modifying the atomic calls [`notify_one`](AtomicWait::notify_one) or
+/// [`notify_all`](AtomicWait::notify_all) after the modification.
+///
+/// The trait is implemented for the atomic types of size four, that is,
+/// [`AtomicU32`](core::sync::atomic::AtomicU32),
+/// [`AtomicI32`](core::sync::atomic::AtomicI32),
+/// [`AtomicF32`](crate::AtomicF32), [`AtomicOptionNonZero`] of
+/// [`NonZeroU32`] and [`NonZeroI32`], and [`AtomicCell`] of types of four
+/// bytes. Values are compared bitwise, so, for example, `-0.0` and `+0.0`
+/// are different, and a NaN is equal to itself.
+///
+/// On Linux and Android the methods use the `futex` system call. On other
+/// platforms waiting threads spin briefly and then block on one of a fixed
+/// number of condition variables selected by the address of the atomic.
+/// Under `cfg(loom)` (see [`sync`](crate::sync)) waiting threads just
+/// yield to the other threads of the model.
+///
+/// # Examples
+///
+/// ```rust
+/// use common_traits::AtomicWait;
+/// use core::sync::atomic::{AtomicU32, Ordering};
+///
+/// // A one-shot latch
+/// let latch = AtomicU32::new(0);
+/// std::thread::scope(|s| {
+///     s.spawn(|| {
+///         latch.store(1, Ordering::Release);
+///         latch.notify_all();
+///     });
+///     while latch.load(Ordering::Acquire) == 0 {
+///         latch.wait(0, None);
+///     }
+/// });
+/// ```
+pub trait AtomicWait: Atomic {
+    /// Blocks the current thread while the atomic contains `expected`,
+    /// until it is woken up by [`notify_one`](AtomicWait::notify_one) or
+    /// [`notify_all`](AtomicWait::notify_all), or until `timeout` (if any)
+    /// expires.
+    ///
+    /// Returns `false` if the timeout expired, and `true` otherwise. The
+    /// method might return spuriously, that is, without a notification and
+    /// with the atomic still containing `expected`, so it should be called
+    /// in a loop checking the condition the thread is waiting for.
+    fn wait(&self, expected: Self::NonAtomicType, timeout: Option<Duration>) -> bool;
+
+    /// Wakes up at least one of the threads waiting on this atomic, if any.
+    fn notify_one(&self);
+
+    /// Wakes up all threads waiting on this atomic.
+    fn notify_all(&self);
+}
+
+mod private {
+    /// Sealed marker of the atomic types of size and alignment four whose
+    /// non-atomic type has size four.
+    pub trait FourBytes {}
+}
+
+use private::FourBytes;
+
+impl FourBytes for AtomicU32 {}
+impl FourBytes for AtomicI32 {}
+impl FourBytes for AtomicF32 {}
+impl FourBytes for AtomicOptionNonZero<NonZeroU32> {}
+impl FourBytes for AtomicOptionNonZero<NonZeroI32> {}
+impl<T: AsBytes<Bytes = [u8; 4]>> FourBytes for AtomicCell<T> {}
+
+/// Returns the bits of a value of a 4-byte atomic type.
+#[inline(always)]
+fn bits<A: Atomic + FourBytes>(value: A::NonAtomicType) -> u32 {
+    // FourBytes guarantees that the non-atomic type has size four
+    let bits = unsafe { core::mem::transmute_copy::<A::NonAtomicType, u32>(&value) };
+    core::mem::forget(value);
+    bits
+}
+
+impl<A: Atomic + FourBytes> AtomicWait for A {
+    fn wait(&self, expected: Self::NonAtomicType, timeout: Option<Duration>) -> bool {
+        let expected = bits::<A>(expected);
+        #[cfg(not(loom))]
+        for _ in 0..SPIN_LIMIT {
+            if bits::<A>(self.load(Ordering::Acquire)) != expected {
+                return true;
+            }
+            core::hint::spin_loop();
+        }
+        sys::wait(self, expected, timeout)
+    }
+
+    #[inline]
+    fn notify_one(&self) {
+        sys::notify(self, false)
+    }
+
+    #[inline]
+    fn notify_all(&self) {
+        sys::notify(self, true)
+    }
+}
+
+#[cfg(all(any(target_os = "linux", target_os = "android"), not(loom)))]
+mod sys {
+    use super::FourBytes;
+    use crate::Atomic;
+    use core::time::Duration;
+
+    pub fn wait<A: Atomic + FourBytes>(
+        atomic: &A,
+        expected: u32,
+        timeout: Option<Duration>,
+    ) -> bool {
+        let timeout = timeout.map(|timeout| libc::timespec {
+            tv_sec: timeout.as_secs().min(libc::time_t::MAX as u64) as libc::time_t,
+            tv_nsec: timeout.subsec_nanos() as _,
+        });
+        let result = unsafe {
+            libc::syscall(
+                libc::SYS_futex,
+                atomic as *const A as *const u32,
+                libc::FUTEX_WAIT | libc::FUTEX_PRIVATE_FLAG,
+                expected,
+                timeout
+                    .as_ref()
+                    .map_or(core::ptr::null(), |timeout| timeout as *const _),
+            )
+        };
+        // EAGAIN (value different from expected) and EINTR are not timeouts
+        !(result < 0 && std::io::Error::last_os_error().raw_os_error() == Some(libc::ETIMEDOUT))
+    }
+
+    pub fn notify<A: Atomic + FourBytes>(atomic: &A, all: bool) {
+        unsafe {
+            libc::syscall(
+                libc::SYS_futex,
+                atomic as *const A as *const u32,
+                libc::FUTEX_WAKE | libc::FUTEX_PRIVATE_FLAG,
+                if all { i32::MAX } else { 1 },
+            );
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", loom)))]
+mod sys {
+    use super::{FourBytes, bits};
+    use crate::Atomic;
+    use core::sync::atomic::Ordering;
+    use core::time::Duration;
+    use std::sync::{Condvar, Mutex};
+
+    const BUCKETS: usize = 64;
+
+    /// A condition variable, padded to avoid false sharing.
+    #[repr(align(64))]
+    struct Bucket {
+        mutex: Mutex<()>,
+        condvar: Condvar,
+    }
+
+    static BUCKET_TABLE: [Bucket; BUCKETS] = [const {
+        Bucket {
+            mutex: Mutex::new(()),
+            condvar: Condvar::new(),
+        }
+    }; BUCKETS];
+
+    fn bucket<A>(atomic: &A) -> &'static Bucket {
+        &BUCKET_TABLE[(atomic as *const A as usize >> 2) % BUCKETS]
+    }
+
+    pub fn wait<A: Atomic + FourBytes>(
+        atomic: &A,
+        expected: u32,
+        timeout: Option<Duration>,
+    ) -> bool {
+        let bucket = bucket(atomic);
+        let guard = bucket.mutex.lock().unwrap_or_else(|e| e.into_inner());
+        // Notifiers lock the mutex after modifying the atomic, so the
+        // notification cannot be lost between this check and the wait
+        if bits::<A>(atomic.load(Ordering::Acquire)) != expected {
+            return true;
+        }
+        match timeout {
+            None => {
+                drop(bucket.condvar.wait(guard));
+                true
+            }
+            Some(timeout) => match bucket.condvar.wait_timeout(guard, timeout) {
+                Ok((_, result)) => !result.timed_out(),
+                Err(e) => !e.into_inner().1.timed_out(),
+            },
+        }
+    }
+
+    pub fn notify<A: Atomic + FourBytes>(atomic: &A, _all: bool) {
+        let bucket = bucket(atomic);
+        drop(bucket.mutex.lock().unwrap_or_else(|e| e.into_inner()));
+        // Different atomics might share the bucket, so we must wake up
+        // everybody to be sure to wake up a waiter on this atomic
+        bucket.condvar.notify_all();
+    }
+}
+
+#[cfg(loom)]
+mod sys {
+    use super::{FourBytes, bits};
+    use crate::Atomic;
+    use core::sync::atomic::Ordering;
+    use core::time::Duration;
+
+    pub fn wait<A: Atomic + FourBytes>(
+        atomic: &A,
+        expected: u32,
+        _timeout: Option<Duration>,
+    ) -> bool {
+        // Loom atomics have no address to wait on, but spurious wake-ups are
+        // allowed, so we just let the other threads run
+        if bits::<A>(atomic.load(Ordering::Acquire)) == expected {
+            loom::thread::yield_now();
+        }
+        true
+    }
+
+    pub fn notify<A: Atomic + FourBytes>(_atomic: &A, _all: bool) {}
+}
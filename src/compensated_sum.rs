@@ -0,0 +1,231 @@
+use crate::atomic::load_ordering;
+use crate::atomic_pair::{PairComponent, PairStorage};
+use crate::{AtomicPair, Float};
+use core::sync::atomic::Ordering;
+
+/// Adds `value` to the sum `sum` with compensation `compensation` using
+/// Neumaier's variant of Kahan's algorithm, returning the new pair.
+#[inline(always)]
+fn neumaier<F: Float>((sum, compensation): (F, F), value: F) -> (F, F) {
+    // Float::abs is not available without std
+    let abs = |x: F| if x < F::ZERO { -x } else { x };
+    let new_sum = sum + value;
+    let error = if abs(sum) >= abs(value) {
+        (sum - new_sum) + value
+    } else {
+        (value - new_sum) + sum
+    };
+    (new_sum, compensation + error)
+}
+
+/// A sum of floats computed using compensated (Kahan–Neumaier) summation.
+///
+/// Besides the sum, the structure keeps a compensation term accumulating the
+/// rounding errors of the additions, so that the error of the
+/// [`value`](CompensatedSum::value) of the sum does not grow with the number
+/// of summands. In particular, the result is much less sensitive to the order
+/// of the summands than a naive sum.
+///
+/// See [`AtomicCompensatedSum`] for a version that can be updated
+/// concurrently.
+///
+/// # Examples
+///
+/// ```rust
+/// use common_traits::CompensatedSum;
+///
+/// let naive = (0..10).fold(1.0_f64, |sum, _| sum + 1E-16);
+/// assert_eq!(naive, 1.0);
+///
+/// let mut sum = CompensatedSum::new(1.0_f64);
+/// for _ in 0..10 {
+///     sum.add(1E-16);
+/// }
+/// assert_eq!(sum.value(), 1.000000000000001);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CompensatedSum<F> {
+    sum: F,
+    compensation: F,
+}
+
+impl<F: Float> CompensatedSum<F> {
+    /// Creates a new compensated sum with the given initial value.
+    #[inline(always)]
+    pub fn new(value: F) -> Self {
+        Self {
+            sum: value,
+            compensation: F::ZERO,
+        }
+    }
+
+    /// Adds `value` to the sum.
+    #[inline(always)]
+    pub fn add(&mut self, value: F) {
+        (self.sum, self.compensation) = neumaier((self.sum, self.compensation), value);
+    }
+
+    /// Returns the value of the sum, that is, the sum corrected by the
+    /// compensation term.
+    #[inline(always)]
+    pub fn value(&self) -> F {
+        self.sum + self.compensation
+    }
+}
+
+impl<F: Float> Default for CompensatedSum<F> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(F::ZERO)
+    }
+}
+
+impl<F: Float> core::ops::AddAssign<F> for CompensatedSum<F> {
+    #[inline(always)]
+    fn add_assign(&mut self, value: F) {
+        self.add(value);
+    }
+}
+
+impl<F: Float> Extend<F> for CompensatedSum<F> {
+    #[inline(always)]
+    fn extend<I: IntoIterator<Item = F>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.add(value));
+    }
+}
+
+impl<F: Float> FromIterator<F> for CompensatedSum<F> {
+    #[inline(always)]
+    fn from_iter<I: IntoIterator<Item = F>>(iter: I) -> Self {
+        let mut sum = Self::default();
+        sum.extend(iter);
+        sum
+    }
+}
+
+/// A [`CompensatedSum`] that can be updated concurrently.
+///
+/// The sum and the compensation term are packed in an [`AtomicPair`], and
+/// they are updated together by a compare-and-swap loop. Pairs of [`f32`] are
+/// stored in an [`AtomicU64`](core::sync::atomic::AtomicU64), whereas pairs of
+/// [`f64`] are stored in an [`AtomicU128`](crate::AtomicU128), which uses a
+/// double-width compare-and-swap if available or a striped lock otherwise.
+/// The floats of the [`half`](https://crates.io/crates/half) crate are
+/// supported if the corresponding feature is enabled.
+///
+/// Concurrent additions are performed in an unspecified order, but thanks
+/// to compensation the result depends much less on the order than the
+/// result of concurrent calls to
+/// [`AtomicNumber::fetch_add`](crate::AtomicNumber::fetch_add).
+///
+/// # Examples
+///
+/// ```rust
+/// use common_traits::AtomicCompensatedSum;
+/// use core::sync::atomic::Ordering;
+///
+/// let sum = AtomicCompensatedSum::new(1.0_f64);
+/// std::thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| {
+///             for _ in 0..1000 {
+///                 sum.add(1E-16, Ordering::Relaxed);
+///             }
+///         });
+///     }
+/// });
+/// assert!((sum.load(Ordering::Relaxed) - (1.0 + 4E-13)).abs() < 1E-16);
+/// ```
+#[repr(transparent)]
+pub struct AtomicCompensatedSum<F>(AtomicPair<F, F>)
+where
+    (F, F): PairStorage;
+
+impl<F: Float + PairComponent> AtomicCompensatedSum<F>
+where
+    (F, F): PairStorage,
+{
+    /// Creates a new atomic compensated sum with the given initial value.
+    #[inline(always)]
+    pub fn new(value: F) -> Self {
+        Self(AtomicPair::new((value, F::ZERO)))
+    }
+
+    /// Adds `value` to the sum.
+    ///
+    /// The ordering has the same meaning as in
+    /// [`AtomicNumber::fetch_add`](crate::AtomicNumber::fetch_add).
+    #[inline(always)]
+    pub fn add(&self, value: F, order: Ordering) {
+        let _ = self.0.fetch_update(order, load_ordering(order), |pair| {
+            Some(neumaier(pair, value))
+        });
+    }
+
+    /// Loads the value of the sum, that is, the sum corrected by the
+    /// compensation term.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is [`Release`](Ordering::Release) or
+    /// [`AcqRel`](Ordering::AcqRel).
+    #[inline(always)]
+    pub fn load(&self, order: Ordering) -> F {
+        self.load_compensated(order).value()
+    }
+
+    /// Loads the sum and the compensation term as a [`CompensatedSum`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is [`Release`](Ordering::Release) or
+    /// [`AcqRel`](Ordering::AcqRel).
+    #[inline(always)]
+    pub fn load_compensated(&self, order: Ordering) -> CompensatedSum<F> {
+        let (sum, compensation) = self.0.load(order);
+        CompensatedSum { sum, compensation }
+    }
+
+    /// Sets the sum to `value`, returning the previous value.
+    #[inline(always)]
+    pub fn swap(&self, value: F, order: Ordering) -> F {
+        let (sum, compensation) = self.0.swap((value, F::ZERO), order);
+        sum + compensation
+    }
+
+    /// Consumes the atomic and returns the value of the sum.
+    #[inline(always)]
+    pub fn into_inner(self) -> F {
+        let (sum, compensation) = self.0.into_inner();
+        sum + compensation
+    }
+}
+
+impl<F: Float + PairComponent> Default for AtomicCompensatedSum<F>
+where
+    (F, F): PairStorage,
+{
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(F::ZERO)
+    }
+}
+
+impl<F: Float + PairComponent> From<CompensatedSum<F>> for AtomicCompensatedSum<F>
+where
+    (F, F): PairStorage,
+{
+    #[inline(always)]
+    fn from(sum: CompensatedSum<F>) -> Self {
+        Self(AtomicPair::new((sum.sum, sum.compensation)))
+    }
+}
+
+impl<F: Float + PairComponent> core::fmt::Debug for AtomicCompensatedSum<F>
+where
+    (F, F): PairStorage,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.load_compensated(Ordering::Relaxed), f)
+    }
+}
@@ -0,0 +1,283 @@
+use crate::{DowncastableFrom, Sequence, SequenceGrowable, To, UpcastableFrom};
+#[cfg(feature = "simd")]
+use core::simd::prelude::*;
+
+/// Bulk version of [`To`], converting a whole slice at once.
+///
+/// The result is the same as calling [`To::to`] on each element, but
+/// conversions between some common pairs of types are faster: `f32`/`f64`
+/// to and from `half::f16`/`half::bf16` use the slice conversions of the
+/// [`half`](https://crates.io/crates/half) crate, and with the `simd` feature
+/// `u8` to `f32`, `i32` to and from `f32`, and `f32` to and from `half::bf16`
+/// are vectorized.
+///
+/// # Examples
+///
+/// ```rust
+/// use common_traits::ConvertSlice;
+///
+/// let src = [1_u8, 2, 3];
+/// let mut dst = [0.0_f32; 3];
+/// u8::convert_slice(&src, &mut dst);
+/// assert_eq!(dst, [1.0, 2.0, 3.0]);
+/// ```
+pub trait ConvertSlice<T>: To<T> + Copy {
+    /// Converts each element of `src` with [`To::to`], storing the result
+    /// in the element of `dst` with the same index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two slices have different lengths.
+    #[inline]
+    fn convert_slice(src: &[Self], dst: &mut [T]) {
+        convert_scalar(src, dst)
+    }
+}
+
+#[inline(always)]
+fn convert_scalar<A: To<B> + Copy, B>(src: &[A], dst: &mut [B]) {
+    assert_eq!(
+        src.len(),
+        dst.len(),
+        "destination and source slices have different lengths"
+    );
+    for (d, &s) in dst.iter_mut().zip(src) {
+        *d = s.to();
+    }
+}
+
+/// Reflexivity
+impl<T: Copy> ConvertSlice<T> for T {
+    #[inline(always)]
+    fn convert_slice(src: &[Self], dst: &mut [T]) {
+        dst.copy_from_slice(src)
+    }
+}
+
+macro_rules! impl_convert_slice {
+    ($($src:ty => $($dst:ty),*;)*) => {$($(
+impl ConvertSlice<$dst> for $src {}
+    )*)*};
+}
+
+// The pairs with a dedicated implementation are omitted
+impl_convert_slice!(
+    u8 => i8, u16, i16, u32, i32, u64, i64, u128, i128, f64, usize, isize;
+    i8 => u8, u16, i16, u32, i32, u64, i64, u128, i128, f32, f64, usize, isize;
+    u16 => u8, i8, i16, u32, i32, u64, i64, u128, i128, f32, f64, usize, isize;
+    i16 => u8, i8, u16, u32, i32, u64, i64, u128, i128, f32, f64, usize, isize;
+    u32 => u8, i8, u16, i16, i32, u64, i64, u128, i128, f32, f64, usize, isize;
+    i32 => u8, i8, u16, i16, u32, u64, i64, u128, i128, f64, usize, isize;
+    u64 => u8, i8, u16, i16, u32, i32, i64, u128, i128, f32, f64, usize, isize;
+    i64 => u8, i8, u16, i16, u32, i32, u64, u128, i128, f32, f64, usize, isize;
+    u128 => u8, i8, u16, i16, u32, i32, u64, i64, i128, f32, f64, usize, isize;
+    i128 => u8, i8, u16, i16, u32, i32, u64, i64, u128, f32, f64, usize, isize;
+    f32 => u8, i8, u16, i16, u32, u64, i64, u128, i128, f64, usize, isize;
+    f64 => u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, f32, usize, isize;
+    usize => u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, f32, f64, isize;
+    isize => u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, f32, f64, usize;
+);
+
+/// Number of lanes used by the vectorized conversions.
+#[cfg(feature = "simd")]
+const LANES: usize = 8;
+
+/// Converts `src` into `dst` applying `$simd` to each full chunk of
+/// [`LANES`] elements and `$scalar` to the remaining ones.
+#[cfg(feature = "simd")]
+macro_rules! convert_simd {
+    ($src:expr, $dst:expr, $a:ty, $b:ty, $simd:expr, $scalar:expr) => {{
+        let (src, dst): (&[$a], &mut [$b]) = ($src, $dst);
+        assert_eq!(
+            src.len(),
+            dst.len(),
+            "destination and source slices have different lengths"
+        );
+        let mut src_chunks = src.chunks_exact(LANES);
+        let mut dst_chunks = dst.chunks_exact_mut(LANES);
+        for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
+            let f: fn(Simd<$a, LANES>) -> Simd<$b, LANES> = $simd;
+            f(Simd::from_slice(s)).copy_to_slice(d);
+        }
+        let f: fn($a) -> $b = $scalar;
+        for (d, &s) in dst_chunks
+            .into_remainder()
+            .iter_mut()
+            .zip(src_chunks.remainder())
+        {
+            *d = f(s);
+        }
+    }};
+}
+
+macro_rules! impl_convert_slice_simd {
+    ($($src:ty => $dst:ty,)*) => {$(
+impl ConvertSlice<$dst> for $src {
+    #[inline]
+    fn convert_slice(src: &[Self], dst: &mut [$dst]) {
+        #[cfg(feature = "simd")]
+        convert_simd!(src, dst, $src, $dst, |x| x.cast(), |x| x as $dst);
+        #[cfg(not(feature = "simd"))]
+        convert_scalar(src, dst);
+    }
+}
+    )*};
+}
+
+impl_convert_slice_simd!(
+    u8 => f32,
+    i32 => f32,
+    f32 => i32,
+);
+
+#[cfg(feature = "half")]
+mod half_impl {
+    use super::*;
+    use half::slice::HalfFloatSliceExt;
+    use half::{bf16, f16};
+
+    macro_rules! impl_convert_slice_half {
+        ($($ty:ty),*) => {$(
+    impl ConvertSlice<f16> for $ty {}
+    impl ConvertSlice<bf16> for $ty {}
+    impl ConvertSlice<$ty> for f16 {}
+    impl ConvertSlice<$ty> for bf16 {}
+        )*};
+    }
+
+    impl_convert_slice_half!(
+        u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize
+    );
+
+    macro_rules! impl_convert_slice_half_float {
+        ($($half:ty, $float:ty, $from:ident, $to:ident;)*) => {$(
+    impl ConvertSlice<$half> for $float {
+        #[inline]
+        fn convert_slice(src: &[Self], dst: &mut [$half]) {
+            dst.$from(src)
+        }
+    }
+    impl ConvertSlice<$float> for $half {
+        #[inline]
+        fn convert_slice(src: &[Self], dst: &mut [$float]) {
+            src.$to(dst)
+        }
+    }
+        )*};
+    }
+
+    impl_convert_slice_half_float!(
+        f16, f32, convert_from_f32_slice, convert_to_f32_slice;
+        f16, f64, convert_from_f64_slice, convert_to_f64_slice;
+        bf16, f64, convert_from_f64_slice, convert_to_f64_slice;
+    );
+
+    impl ConvertSlice<bf16> for f32 {
+        #[inline]
+        fn convert_slice(src: &[Self], dst: &mut [bf16]) {
+            #[cfg(feature = "simd")]
+            {
+                let dst = dst.reinterpret_cast_mut();
+                convert_simd!(
+                    src,
+                    dst,
+                    f32,
+                    u16,
+                    |x| {
+                        // Same rounding as bf16::from_f32: to nearest, ties to
+                        // even, quieting NaNs
+                        let x = x.to_bits();
+                        let round_bit = Simd::splat(0x8000);
+                        let round_up = (x & round_bit).simd_ne(Simd::splat(0))
+                            & (x & Simd::splat(3 * 0x8000 - 1)).simd_ne(Simd::splat(0));
+                        let rounded = (x >> 16) + round_up.select(Simd::splat(1), Simd::splat(0));
+                        let nan = (x & Simd::splat(0x7FFF_FFFF)).simd_gt(Simd::splat(0x7F80_0000));
+                        nan.select((x >> 16) | Simd::splat(0x0040), rounded).cast()
+                    },
+                    |x| bf16::from_f32(x).to_bits()
+                );
+            }
+            #[cfg(not(feature = "simd"))]
+            dst.convert_from_f32_slice(src);
+        }
+    }
+
+    impl ConvertSlice<f32> for bf16 {
+        #[inline]
+        fn convert_slice(src: &[Self], dst: &mut [f32]) {
+            #[cfg(feature = "simd")]
+            {
+                let src = src.reinterpret_cast();
+                convert_simd!(
+                    src,
+                    dst,
+                    u16,
+                    f32,
+                    |x| {
+                        // Same as bf16::to_f32, quieting NaNs
+                        let x: Simd<u32, LANES> = x.cast();
+                        let nan = (x & Simd::splat(0x7FFF)).simd_gt(Simd::splat(0x7F80));
+                        Simd::from_bits(nan.select(x | Simd::splat(0x0040), x) << 16)
+                    },
+                    |x| bf16::from_bits(x).to_f32()
+                );
+            }
+            #[cfg(not(feature = "simd"))]
+            src.convert_to_f32_slice(dst);
+        }
+    }
+}
+
+/// Extension trait converting the elements of a [`Sequence`] and
+/// appending them to a [`SequenceGrowable`].
+///
+/// It is implemented for all sequences.
+///
+/// # Examples
+///
+/// ```rust
+/// use common_traits::ConvertSequence;
+///
+/// let src = vec![1_u16, 2, 3];
+/// let mut dst: Vec<u64> = vec![0];
+/// src.upcast_into(&mut dst);
+/// assert_eq!(dst, vec![0, 1, 2, 3]);
+/// ```
+pub trait ConvertSequence: Sequence {
+    /// Appends the elements of `self` to `dst`, converting them using [`To`].
+    #[inline]
+    fn convert_into<G: SequenceGrowable + ?Sized>(&self, dst: &mut G)
+    where
+        Self::Item: To<G::Item>,
+    {
+        for value in self.iter() {
+            dst.push(value.to());
+        }
+    }
+
+    /// Appends the elements of `self` to `dst`, converting them using
+    /// [`UpcastableFrom`].
+    #[inline]
+    fn upcast_into<G: SequenceGrowable + ?Sized>(&self, dst: &mut G)
+    where
+        G::Item: UpcastableFrom<Self::Item>,
+    {
+        for value in self.iter() {
+            dst.push(G::Item::upcast_from(value));
+        }
+    }
+
+    /// Appends the elements of `self` to `dst`, converting them using
+    /// [`DowncastableFrom`].
+    #[inline]
+    fn downcast_into<G: SequenceGrowable + ?Sized>(&self, dst: &mut G)
+    where
+        G::Item: DowncastableFrom<Self::Item>,
+    {
+        for value in self.iter() {
+            dst.push(G::Item::downcast_from(value));
+        }
+    }
+}
+
+impl<S: Sequence + ?Sized> ConvertSequence for S {}
@@ -22,3 +22,72 @@ pub trait Hash {
     /// Feeds this value into the given [`Hasher`].
     fn hash<H: Hasher>(&self, state: &mut H);
 }
+
+/// Numbers are written in little-endian order, so that hashes do not depend
+/// on the endianness of the platform.
+macro_rules! impl_hash {
+    ($($ty:ty),*) => {$(
+        impl Hash for $ty {
+            #[inline(always)]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                state.write(&self.to_le_bytes());
+            }
+        }
+    )*};
+}
+
+impl_hash!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+impl Hash for bool {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write(&[*self as u8]);
+    }
+}
+
+impl Hash for char {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (*self as u32).hash(state);
+    }
+}
+
+impl Hash for str {
+    /// Writes the bytes of the string followed by `0xFF`, which cannot
+    /// appear in UTF-8, so that the concatenation of hashed strings is
+    /// unambiguous, as in [`core::hash::Hash`].
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write(self.as_bytes());
+        state.write(&[0xFF]);
+    }
+}
+
+impl<T: Hash> Hash for [T] {
+    /// Writes the length of the slice, as a [`u64`], followed by its
+    /// elements.
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // usize has a different size on different platforms
+        (self.len() as u64).hash(state);
+        for item in self {
+            item.hash(state);
+        }
+    }
+}
+
+impl<T: Hash, const N: usize> Hash for [T; N] {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self[..].hash(state);
+    }
+}
+
+impl<T: Hash + ?Sized> Hash for &T {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state);
+    }
+}
@@ -1,20 +1,25 @@
 use crate::{
-    AsBytes, Atomic, AtomicF32, AtomicF64, AtomicFiniteRangeNumber, AtomicFloat, AtomicInteger,
-    AtomicNumber, AtomicSignedInt, AtomicUnsignedInt, False, FiniteRangeNumber, Float, FromBytes,
-    Integer, IntoAtomic, IsAtomic, IsFloat, IsInteger, IsNonZero, IsSigned, Number, SignedInt,
-    ToBytes, True, UnsignedInt,
+    AsBytes, Atomic, AtomicF32, AtomicF64, AtomicFiniteRangeNumber, AtomicFloat, AtomicI128,
+    AtomicInteger, AtomicNumber, AtomicSignedInt, AtomicU128, AtomicUnsignedInt, False,
+    FiniteRangeNumber, Float, FromBytes, Integer, IntoAtomic, IsAtomic, IsFloat, IsInteger,
+    IsNonZero, IsSigned, Number, SignedInt, ToBytes, True, UnsignedInt,
 };
 
+#[cfg(feature = "half")]
+use crate::atomic_float::impl_total_min_max;
+#[cfg(loom)]
+use crate::sync::check_layout;
+use crate::sync::{
+    AtomicBool, AtomicI8, AtomicI16, AtomicI32, AtomicI64, AtomicIsize, AtomicU8, AtomicU16,
+    AtomicU32, AtomicU64, AtomicUsize,
+};
 #[cfg(feature = "half")]
 use crate::{AtomicBF16, AtomicF16};
 use core::num::{
     FpCategory, NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
     NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
 };
-use core::sync::atomic::{
-    AtomicBool, AtomicI8, AtomicI16, AtomicI32, AtomicI64, AtomicIsize, AtomicU8, AtomicU16,
-    AtomicU32, AtomicU64, AtomicUsize, Ordering,
-};
+use core::sync::atomic::Ordering;
 
 impl<T: Atomic + AsBytes> FromBytes for T
 where
@@ -153,26 +158,41 @@ macro_rules! impl_into_atomic {
 
             #[inline(always)]
             fn from_atomic_array<const N: usize>(data: [Self::AtomicType; N]) -> [Self; N] {
-                unsafe { *(data.as_ptr() as *const [Self; N]) }
+                #[cfg(not(loom))]
+                unsafe {
+                    *(data.as_ptr() as *const [Self; N])
+                }
+                #[cfg(loom)]
+                {
+                    data.map(Self::AtomicType::into_inner)
+                }
             }
 
             #[inline(always)]
             fn get_mut_slice(this: &mut [Self::AtomicType]) -> &mut [Self] {
+                #[cfg(loom)]
+                check_layout();
                 unsafe { core::mem::transmute(this) }
             }
 
             #[inline(always)]
             fn from_mut_slice(this: &mut [Self]) -> &mut [Self::AtomicType] {
+                #[cfg(loom)]
+                check_layout();
                 unsafe { core::mem::transmute(this) }
             }
 
             #[inline(always)]
             fn get_mut_array<const N: usize>(this: &mut [Self::AtomicType; N]) -> &mut [Self; N] {
+                #[cfg(loom)]
+                check_layout();
                 unsafe { core::mem::transmute(this) }
             }
 
             #[inline(always)]
             fn from_mut_array<const N: usize>(this: &mut [Self; N]) -> &mut [Self::AtomicType; N] {
+                #[cfg(loom)]
+                check_layout();
                 unsafe { core::mem::transmute(this) }
             }
         }
@@ -195,11 +215,18 @@ macro_rules! impl_into_atomic {
                 <$aty>::store(self, value, order)
             }
 
+            #[cfg(not(loom))]
             #[inline(always)]
             fn get_mut(&mut self) -> &mut Self::NonAtomicType {
                 <$aty>::get_mut(self)
             }
 
+            #[cfg(loom)]
+            fn get_mut(&mut self) -> &mut Self::NonAtomicType {
+                check_layout();
+                unsafe { &mut *(self as *mut Self as *mut Self::NonAtomicType) }
+            }
+
             #[inline(always)]
             fn into_inner(self) -> Self::NonAtomicType {
                 <$aty>::into_inner(self)
@@ -207,7 +234,14 @@ macro_rules! impl_into_atomic {
 
             #[inline(always)]
             fn into_non_atomic_array<const N: usize>(data: [Self; N]) -> [Self::NonAtomicType; N] {
-                unsafe { *(data.as_ptr() as *const [Self::NonAtomicType; N]) }
+                #[cfg(not(loom))]
+                unsafe {
+                    *(data.as_ptr() as *const [Self::NonAtomicType; N])
+                }
+                #[cfg(loom)]
+                {
+                    data.map(Self::into_inner)
+                }
             }
 
             #[inline(always)]
@@ -217,11 +251,15 @@ macro_rules! impl_into_atomic {
 
             #[inline(always)]
             fn get_mut_slice(this: &mut [Self]) -> &mut [Self::NonAtomicType] {
+                #[cfg(loom)]
+                check_layout();
                 unsafe { core::mem::transmute::<&mut [Self], &mut [Self::NonAtomicType]>(this) }
             }
 
             #[inline(always)]
             fn from_mut_slice(this: &mut [Self::NonAtomicType]) -> &mut [Self] {
+                #[cfg(loom)]
+                check_layout();
                 unsafe { core::mem::transmute::<&mut [Self::NonAtomicType], &mut [Self]>(this) }
             }
 
@@ -229,6 +267,8 @@ macro_rules! impl_into_atomic {
             fn get_mut_array<const N: usize>(
                 this: &mut [Self; N],
             ) -> &mut [Self::NonAtomicType; N] {
+                #[cfg(loom)]
+                check_layout();
                 unsafe {
                     core::mem::transmute::<&mut [Self; N], &mut [Self::NonAtomicType; N]>(this)
                 }
@@ -238,6 +278,8 @@ macro_rules! impl_into_atomic {
             fn from_mut_array<const N: usize>(
                 this: &mut [Self::NonAtomicType; N],
             ) -> &mut [Self; N] {
+                #[cfg(loom)]
+                check_layout();
                 unsafe {
                     core::mem::transmute::<&mut [Self::NonAtomicType; N], &mut [Self; N]>(this)
                 }
@@ -755,18 +797,6 @@ macro_rules! impl_unsigned_int {
     };
 }
 
-// We implement separately IsAtomic for u128
-// because this is done for the rest of the
-// scalar types in impl_into_atomic!,
-// and there is no AtomicU128 type.
-
-impl IsAtomic for u128 {
-    type Atomic = False;
-}
-impl IsAtomic for i128 {
-    type Atomic = False;
-}
-
 impl_unsigned_int!(u8, i8, NonZeroU8, NonZeroI8);
 impl_unsigned_int!(u16, i16, NonZeroU16, NonZeroI16);
 impl_unsigned_int!(u32, i32, NonZeroU32, NonZeroI32);
@@ -779,34 +809,40 @@ impl_into_atomic!(u16, AtomicU16);
 impl_into_atomic!(u32, AtomicU32);
 impl_into_atomic!(u64, AtomicU64);
 impl_into_atomic!(usize, AtomicUsize);
+impl_into_atomic!(u128, AtomicU128);
 
 impl_into_atomic!(i8, AtomicI8);
 impl_into_atomic!(i16, AtomicI16);
 impl_into_atomic!(i32, AtomicI32);
 impl_into_atomic!(i64, AtomicI64);
 impl_into_atomic!(isize, AtomicIsize);
+impl_into_atomic!(i128, AtomicI128);
 
 impl_atomic_integer!(AtomicI8);
 impl_atomic_integer!(AtomicI16);
 impl_atomic_integer!(AtomicI32);
 impl_atomic_integer!(AtomicI64);
 impl_atomic_integer!(AtomicIsize);
+impl_atomic_integer!(AtomicI128);
 impl_atomic_integer!(AtomicU8);
 impl_atomic_integer!(AtomicU16);
 impl_atomic_integer!(AtomicU32);
 impl_atomic_integer!(AtomicU64);
 impl_atomic_integer!(AtomicUsize);
+impl_atomic_integer!(AtomicU128);
 
 impl_atomic_signed_int!(AtomicI8);
 impl_atomic_signed_int!(AtomicI16);
 impl_atomic_signed_int!(AtomicI32);
 impl_atomic_signed_int!(AtomicI64);
 impl_atomic_signed_int!(AtomicIsize);
+impl_atomic_signed_int!(AtomicI128);
 impl_atomic_unsigned_int!(AtomicU8);
 impl_atomic_unsigned_int!(AtomicU16);
 impl_atomic_unsigned_int!(AtomicU32);
 impl_atomic_unsigned_int!(AtomicU64);
 impl_atomic_unsigned_int!(AtomicUsize);
+impl_atomic_unsigned_int!(AtomicU128);
 
 impl IsAtomic for bool {
     type Atomic = False;
@@ -854,26 +890,41 @@ impl IntoAtomic for bool {
 
     #[inline(always)]
     fn from_atomic_array<const N: usize>(data: [Self::AtomicType; N]) -> [Self; N] {
-        unsafe { *(data.as_ptr() as *const [Self; N]) }
+        #[cfg(not(loom))]
+        unsafe {
+            *(data.as_ptr() as *const [Self; N])
+        }
+        #[cfg(loom)]
+        {
+            data.map(Self::AtomicType::into_inner)
+        }
     }
 
     #[inline(always)]
     fn get_mut_slice(this: &mut [Self::AtomicType]) -> &mut [Self] {
+        #[cfg(loom)]
+        check_layout();
         unsafe { core::mem::transmute(this) }
     }
 
     #[inline(always)]
     fn from_mut_slice(this: &mut [Self]) -> &mut [Self::AtomicType] {
+        #[cfg(loom)]
+        check_layout();
         unsafe { core::mem::transmute(this) }
     }
 
     #[inline(always)]
     fn get_mut_array<const N: usize>(this: &mut [Self::AtomicType; N]) -> &mut [Self; N] {
+        #[cfg(loom)]
+        check_layout();
         unsafe { core::mem::transmute(this) }
     }
 
     #[inline(always)]
     fn from_mut_array<const N: usize>(this: &mut [Self; N]) -> &mut [Self::AtomicType; N] {
+        #[cfg(loom)]
+        check_layout();
         unsafe { core::mem::transmute(this) }
     }
 }
@@ -896,11 +947,18 @@ impl Atomic for AtomicBool {
         <Self>::store(self, value, order)
     }
 
+    #[cfg(not(loom))]
     #[inline(always)]
     fn get_mut(&mut self) -> &mut Self::NonAtomicType {
         <Self>::get_mut(self)
     }
 
+    #[cfg(loom)]
+    fn get_mut(&mut self) -> &mut Self::NonAtomicType {
+        check_layout();
+        unsafe { &mut *(self as *mut Self as *mut Self::NonAtomicType) }
+    }
+
     #[inline(always)]
     fn into_inner(self) -> Self::NonAtomicType {
         <Self>::into_inner(self)
@@ -908,7 +966,14 @@ impl Atomic for AtomicBool {
 
     #[inline(always)]
     fn into_non_atomic_array<const N: usize>(data: [Self; N]) -> [Self::NonAtomicType; N] {
-        unsafe { *(data.as_ptr() as *const [Self::NonAtomicType; N]) }
+        #[cfg(not(loom))]
+        unsafe {
+            *(data.as_ptr() as *const [Self::NonAtomicType; N])
+        }
+        #[cfg(loom)]
+        {
+            data.map(Self::into_inner)
+        }
     }
 
     #[inline(always)]
@@ -918,20 +983,28 @@ impl Atomic for AtomicBool {
 
     #[inline(always)]
     fn get_mut_slice(this: &mut [Self]) -> &mut [Self::NonAtomicType] {
+        #[cfg(loom)]
+        check_layout();
         unsafe { core::mem::transmute::<&mut [Self], &mut [Self::NonAtomicType]>(this) }
     }
 
     #[inline(always)]
     fn from_mut_slice(this: &mut [Self::NonAtomicType]) -> &mut [Self] {
+        #[cfg(loom)]
+        check_layout();
         unsafe { core::mem::transmute::<&mut [Self::NonAtomicType], &mut [Self]>(this) }
     }
 
     #[inline(always)]
     fn get_mut_array<const N: usize>(this: &mut [Self; N]) -> &mut [Self::NonAtomicType; N] {
+        #[cfg(loom)]
+        check_layout();
         unsafe { core::mem::transmute::<&mut [Self; N], &mut [Self::NonAtomicType; N]>(this) }
     }
     #[inline(always)]
     fn from_mut_array<const N: usize>(this: &mut [Self::NonAtomicType; N]) -> &mut [Self; N] {
+        #[cfg(loom)]
+        check_layout();
         unsafe { core::mem::transmute::<&mut [Self::NonAtomicType; N], &mut [Self; N]>(this) }
     }
 
@@ -1038,26 +1111,39 @@ impl IntoAtomic for $ty {
 
     #[inline(always)]
     fn from_atomic_array<const N: usize>(data: [Self::AtomicType; N]) -> [Self; N] {
+        #[cfg(not(loom))]
         unsafe { *(data.as_ptr() as *const [Self; N]) }
+        #[cfg(loom)]
+        {
+            data.map(Self::AtomicType::into_inner)
+        }
     }
 
     #[inline(always)]
     fn get_mut_slice(this: &mut [Self::AtomicType]) -> &mut [Self] {
+        #[cfg(loom)]
+        check_layout();
         unsafe { core::mem::transmute(this) }
     }
 
     #[inline(always)]
     fn from_mut_slice(this: &mut [Self]) -> &mut [Self::AtomicType] {
+        #[cfg(loom)]
+        check_layout();
         unsafe { core::mem::transmute(this) }
     }
 
     #[inline(always)]
     fn get_mut_array<const N: usize>(this: &mut [Self::AtomicType; N]) -> &mut [Self; N] {
+        #[cfg(loom)]
+        check_layout();
         unsafe { core::mem::transmute(this) }
     }
 
     #[inline(always)]
     fn from_mut_array<const N: usize>(this: &mut [Self; N]) -> &mut [Self::AtomicType; N] {
+        #[cfg(loom)]
+        check_layout();
         unsafe { core::mem::transmute(this) }
     }
 
@@ -1452,26 +1538,41 @@ macro_rules! impl_f16 {
 
             #[inline(always)]
             fn from_atomic_array<const N: usize>(data: [Self::AtomicType; N]) -> [Self; N] {
-                unsafe { *(data.as_ptr() as *const [Self; N]) }
+                #[cfg(not(loom))]
+                unsafe {
+                    *(data.as_ptr() as *const [Self; N])
+                }
+                #[cfg(loom)]
+                {
+                    data.map(Self::AtomicType::into_inner)
+                }
             }
 
             #[inline(always)]
             fn get_mut_slice(this: &mut [Self::AtomicType]) -> &mut [Self] {
+                #[cfg(loom)]
+                check_layout();
                 unsafe { core::mem::transmute(this) }
             }
 
             #[inline(always)]
             fn from_mut_slice(this: &mut [Self]) -> &mut [Self::AtomicType] {
+                #[cfg(loom)]
+                check_layout();
                 unsafe { core::mem::transmute(this) }
             }
 
             #[inline(always)]
             fn get_mut_array<const N: usize>(this: &mut [Self::AtomicType; N]) -> &mut [Self; N] {
+                #[cfg(loom)]
+                check_layout();
                 unsafe { core::mem::transmute(this) }
             }
 
             #[inline(always)]
             fn from_mut_array<const N: usize>(this: &mut [Self; N]) -> &mut [Self::AtomicType; N] {
+                #[cfg(loom)]
+                check_layout();
                 unsafe { core::mem::transmute(this) }
             }
         }
@@ -1496,6 +1597,8 @@ macro_rules! impl_f16 {
 
             #[inline(always)]
             fn get_mut(&mut self) -> &mut Self::NonAtomicType {
+                #[cfg(loom)]
+                check_layout();
                 unsafe { &mut *(self as *mut Self as *mut Self::NonAtomicType) }
             }
 
@@ -1506,7 +1609,14 @@ macro_rules! impl_f16 {
 
             #[inline(always)]
             fn into_non_atomic_array<const N: usize>(data: [Self; N]) -> [Self::NonAtomicType; N] {
-                unsafe { *(data.as_ptr() as *const [Self::NonAtomicType; N]) }
+                #[cfg(not(loom))]
+                unsafe {
+                    *(data.as_ptr() as *const [Self::NonAtomicType; N])
+                }
+                #[cfg(loom)]
+                {
+                    data.map(Self::into_inner)
+                }
             }
 
             #[inline(always)]
@@ -1516,11 +1626,15 @@ macro_rules! impl_f16 {
 
             #[inline(always)]
             fn get_mut_slice(this: &mut [Self]) -> &mut [Self::NonAtomicType] {
+                #[cfg(loom)]
+                check_layout();
                 unsafe { core::mem::transmute::<&mut [Self], &mut [Self::NonAtomicType]>(this) }
             }
 
             #[inline(always)]
             fn from_mut_slice(this: &mut [Self::NonAtomicType]) -> &mut [Self] {
+                #[cfg(loom)]
+                check_layout();
                 unsafe { core::mem::transmute::<&mut [Self::NonAtomicType], &mut [Self]>(this) }
             }
 
@@ -1528,6 +1642,8 @@ macro_rules! impl_f16 {
             fn get_mut_array<const N: usize>(
                 this: &mut [Self; N],
             ) -> &mut [Self::NonAtomicType; N] {
+                #[cfg(loom)]
+                check_layout();
                 unsafe {
                     core::mem::transmute::<&mut [Self; N], &mut [Self::NonAtomicType; N]>(this)
                 }
@@ -1536,6 +1652,8 @@ macro_rules! impl_f16 {
             fn from_mut_array<const N: usize>(
                 this: &mut [Self::NonAtomicType; N],
             ) -> &mut [Self; N] {
+                #[cfg(loom)]
+                check_layout();
                 unsafe {
                     core::mem::transmute::<&mut [Self::NonAtomicType; N], &mut [Self; N]>(this)
                 }
@@ -2061,368 +2179,7 @@ macro_rules! impl_f16 {
         }
 
         impl AtomicFloat for $aty {
-            #[inline(always)]
-            fn is_nan(&self, order: Ordering) -> bool {
-                Self::NonAtomicType::from_bits(self.0.load(order)).is_nan()
-            }
-            #[inline(always)]
-            fn is_infinite(&self, order: Ordering) -> bool {
-                Self::NonAtomicType::from_bits(self.0.load(order)).is_infinite()
-            }
-            #[inline(always)]
-            fn is_finite(&self, order: Ordering) -> bool {
-                Self::NonAtomicType::from_bits(self.0.load(order)).is_finite()
-            }
-            #[inline(always)]
-            fn is_subnormal(&self, order: Ordering) -> bool {
-                Self::NonAtomicType::from_bits(self.0.load(order)).is_subnormal()
-            }
-            #[inline(always)]
-            fn is_normal(&self, order: Ordering) -> bool {
-                Self::NonAtomicType::from_bits(self.0.load(order)).is_normal()
-            }
-            #[inline(always)]
-            fn classify(&self, order: Ordering) -> FpCategory {
-                Self::NonAtomicType::from_bits(self.0.load(order)).classify()
-            }
-            #[inline(always)]
-            fn is_sign_positive(&self, order: Ordering) -> bool {
-                Self::NonAtomicType::from_bits(self.0.load(order)).is_sign_positive()
-            }
-            #[inline(always)]
-            fn is_sign_negative(&self, order: Ordering) -> bool {
-                Self::NonAtomicType::from_bits(self.0.load(order)).is_sign_negative()
-            }
-            #[inline(always)]
-            fn fetch_recip(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).recip().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[inline(always)]
-            fn fetch_to_degrees(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).to_degrees().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[inline(always)]
-            fn fetch_to_radians(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).to_radians().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_div_euclid(&self, rhs: Self::NonAtomicType, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).div_euclid(rhs).to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_rem_euclid(&self, rhs: Self::NonAtomicType, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).rem_euclid(rhs).to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_floor(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).floor().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_ceil(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).ceil().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_round(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).round().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_trunc(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).trunc().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_fract(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).fract().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_abs(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).abs().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_signum(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).signum().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_copysign(&self, sign: Self::NonAtomicType, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).copysign(sign).to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_powi(&self, n: isize, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).powi(n).to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_powf(&self, n: Self::NonAtomicType, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).powf(n).to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_sqrt(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).sqrt().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_exp(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).exp().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_exp2(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).exp2().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_ln(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).ln().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_log(&self, base: Self::NonAtomicType, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).log(base).to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_log2(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).log2().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_log10(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).log10().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_cbrt(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).cbrt().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_sin(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).sin().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_cos(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).cos().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_tan(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).tan().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_asin(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).asin().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_acos(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).acos().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_atan(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).atan().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_exp_m1(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).exp_m1().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_ln_1p(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).ln_1p().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_sinh(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).sinh().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_cosh(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).cosh().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_tanh(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).tanh().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_asinh(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).asinh().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_acosh(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).acosh().to_bits())
-                    })
-                    .unwrap();
-            }
-            #[cfg(feature = "std")]
-            #[inline(always)]
-            fn fetch_atanh(&self, order: Ordering) {
-                self.0
-                    .fetch_update(Ordering::Relaxed, order, |x| {
-                        Some(Self::NonAtomicType::from_bits(x).atanh().to_bits())
-                    })
-                    .unwrap();
-            }
+            impl_total_min_max!(AtomicU16, AtomicI16);
         }
     };
 }
@@ -36,6 +36,8 @@ pub use signed_int::SignedInt;
 mod atomic;
 pub use atomic::{Atomic, IntoAtomic};
 
+pub mod sync;
+
 mod float;
 pub use float::Float;
 
@@ -55,11 +57,53 @@ pub use atomic_number::AtomicNumber;
 mod atomic_integer;
 pub use atomic_integer::{AtomicInteger, AtomicSignedInt, AtomicUnsignedInt};
 
+mod atomic_128;
+pub use atomic_128::{AtomicI128, AtomicU128};
+
+mod atomic_option_non_zero;
+pub use atomic_option_non_zero::AtomicOptionNonZero;
+
+mod atomic_cell;
+pub use atomic_cell::AtomicCell;
+
+mod atomic_pair;
+pub use atomic_pair::AtomicPair;
+
+mod atomic_bit_set;
+pub use atomic_bit_set::{AtomicBitSet, IterOnes};
+
+mod striped_counter;
+pub use striped_counter::{AtomicCounter, StripedCounter};
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod atomic_histogram;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use atomic_histogram::{AtomicHistogram, BucketLayout};
+
+mod compensated_sum;
+pub use compensated_sum::{AtomicCompensatedSum, CompensatedSum};
+
+#[cfg(feature = "std")]
+mod atomic_wait;
+#[cfg(feature = "std")]
+pub use atomic_wait::AtomicWait;
+
+mod seq_lock_atomic;
+pub use seq_lock_atomic::SeqLockAtomic;
+
+#[cfg(all(feature = "std", any(target_os = "linux", target_os = "android")))]
+mod shared_atomics;
+#[cfg(all(feature = "std", any(target_os = "linux", target_os = "android")))]
+pub use shared_atomics::SharedAtomics;
+
 mod impls;
 
 mod rnd;
 pub use rnd::{Rng, RngNext};
 
+mod atomic_rng;
+pub use atomic_rng::{AtomicRng, SplitMix64};
+
 mod same_as;
 pub use same_as::SameAs;
 
@@ -72,9 +116,17 @@ pub use splat::Splat;
 mod sequence;
 pub use sequence::{Sequence, SequenceGrowable, SequenceMut};
 
+mod atomic_sequence;
+pub use atomic_sequence::{AtomicSequence, WithOrdering, WithOrderingIter};
+
 mod hash;
 pub use hash::{Hash, Hasher, SeedableHasher};
 
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod sketch;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use sketch::{BloomFilter, CountMinSketch, CountingBloomFilter, HyperLogLog};
+
 mod upcastable;
 pub use upcastable::{UpcastableFrom, UpcastableInto};
 
@@ -84,6 +136,23 @@ pub use downcastable::{DowncastableFrom, DowncastableInto};
 mod castable;
 pub use castable::{CastableFrom, CastableInto};
 
+mod lossless;
+pub use lossless::{LosslessFrom, LosslessInto};
+
+mod convert;
+pub use convert::{ConvertSequence, ConvertSlice};
+
+mod num_cmp;
+pub use num_cmp::NumCmp;
+
+mod promote;
+pub use promote::Promote;
+
+#[cfg(feature = "half")]
+mod stochastic_rounding;
+#[cfg(feature = "half")]
+pub use stochastic_rounding::{StochasticRoundFrom, StochasticRoundInto};
+
 /// A trait for types that have a fixed-length representation as a sequence of bytes.
 /// This includes all standard numerical scalar types.
 ///
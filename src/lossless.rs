@@ -0,0 +1,113 @@
+/// [`LosslessInto`] : [`LosslessFrom`] = [`Into`] : [`From`]. It's easier to
+/// use to specify bounds on generic variables.
+pub trait LosslessInto<W>: Sized {
+    /// Calls `W::lossless_from(self)`.
+    fn lossless(self) -> W;
+}
+
+/// Trait for primitive numeric types that can be converted without loss of
+/// information on every supported target.
+///
+/// Differently from [`UpcastableFrom`](crate::UpcastableFrom), whose
+/// implementations involving [`usize`] and [`isize`] depend on the pointer
+/// width of the target, this trait is implemented only for conversions that
+/// are lossless on all 16-, 32- and 64-bit targets. For example, `u32` is
+/// [`UpcastableFrom<usize>`](crate::UpcastableFrom) on 32-bit targets, but it
+/// is never `LosslessFrom<usize>`, so code using this trait cannot silently
+/// truncate when compiled for a different target.
+pub trait LosslessFrom<W>: Sized {
+    /// Converts `value` to `Self` without loss of information.
+    fn lossless_from(value: W) -> Self;
+}
+
+/// [`LosslessFrom`] implies [`LosslessInto`].
+impl<T, U> LosslessInto<U> for T
+where
+    U: LosslessFrom<T>,
+{
+    #[inline(always)]
+    fn lossless(self) -> U {
+        U::lossless_from(self)
+    }
+}
+
+/// Reflexivity
+impl<T> LosslessFrom<T> for T {
+    #[inline(always)]
+    fn lossless_from(value: T) -> Self {
+        value
+    }
+}
+
+macro_rules! impl_lossless {
+    ($($base_type:ty => $($ty:ty),*;)*) => {$($(
+impl LosslessFrom<$base_type> for $ty {
+    #[inline(always)]
+    fn lossless_from(value: $base_type) -> Self {
+        value as $ty
+    }
+}
+    )*)*};
+}
+
+impl_lossless!(
+    u8 => u16, u32, u64, u128, i16, i32, i64, i128, f32, f64;
+    u16 => u32, u64, u128, i32, i64, i128, f32, f64;
+    u32 => u64, u128, i64, i128, f64;
+    u64 => u128, i128;
+    i8 => i16, i32, i64, i128, f32, f64;
+    i16 => i32, i64, i128, f32, f64;
+    i32 => i64, i128, f64;
+    i64 => i128;
+    f32 => f64;
+);
+
+#[cfg(any(
+    target_pointer_width = "16",
+    target_pointer_width = "32",
+    target_pointer_width = "64",
+))]
+impl_lossless!(
+    u8 => usize, isize;
+    u16 => usize;
+    i8 => isize;
+    i16 => isize;
+    usize => u64, u128, i128;
+    isize => i64, i128;
+);
+
+#[cfg(feature = "half")]
+mod half_impl {
+    use super::*;
+
+    macro_rules! impl_lossless_half {
+        ($($ty:ty),*) => {$(
+    impl LosslessFrom<$ty> for f32 {
+        #[inline(always)]
+        fn lossless_from(value: $ty) -> Self {
+            value.to_f32()
+        }
+    }
+    impl LosslessFrom<$ty> for f64 {
+        #[inline(always)]
+        fn lossless_from(value: $ty) -> Self {
+            value.to_f64()
+        }
+    }
+    impl LosslessFrom<u8> for $ty {
+        #[inline(always)]
+        fn lossless_from(value: u8) -> Self {
+            <$ty>::from_f32(value as f32)
+        }
+    }
+    impl LosslessFrom<i8> for $ty {
+        #[inline(always)]
+        fn lossless_from(value: i8) -> Self {
+            <$ty>::from_f32(value as f32)
+        }
+    }
+        )*};
+    }
+
+    impl_lossless_half!(half::f16, half::bf16);
+}
@@ -0,0 +1,203 @@
+use core::cmp::Ordering;
+
+/// Exact comparison between primitive numbers of possibly different types.
+///
+/// Comparing numbers of different types after casting them to a common type
+/// might give wrong results: for example, `u64::MAX as f64 == (u64::MAX - 1)
+/// as f64`, and `-1_i64 as u64 > 0`. The methods of this trait compare the
+/// mathematical values of the two arguments instead, without any rounding.
+/// NaNs are not comparable with any value, as with [`PartialOrd`].
+///
+/// It is implemented for all pairs of primitive integers and floats (and the
+/// floats of the [`half`](https://crates.io/crates/half) crate if the
+/// corresponding feature is enabled).
+///
+/// # Examples
+///
+/// ```rust
+/// use common_traits::NumCmp;
+///
+/// // u64::MAX as f64 is rounded to 2^64
+/// assert!(u64::MAX.num_lt(u64::MAX as f64));
+/// assert!((-1_i64).num_lt(0_u64));
+/// assert!(i128::MAX.num_lt(f32::MAX));
+/// assert!(!f64::NAN.num_eq(f64::NAN));
+/// assert_eq!(3_u8.num_cmp(3.0_f32), Some(core::cmp::Ordering::Equal));
+/// ```
+pub trait NumCmp<Rhs = Self>: Sized {
+    /// Returns the ordering between `self` and `other`, or `None` if one of
+    /// them is NaN.
+    fn num_cmp(self, other: Rhs) -> Option<Ordering>;
+
+    /// Returns whether `self` is equal to `other`.
+    #[inline(always)]
+    fn num_eq(self, other: Rhs) -> bool {
+        self.num_cmp(other) == Some(Ordering::Equal)
+    }
+
+    /// Returns whether `self` is not equal to `other`.
+    #[inline(always)]
+    fn num_ne(self, other: Rhs) -> bool {
+        !self.num_eq(other)
+    }
+
+    /// Returns whether `self` is less than `other`.
+    #[inline(always)]
+    fn num_lt(self, other: Rhs) -> bool {
+        self.num_cmp(other) == Some(Ordering::Less)
+    }
+
+    /// Returns whether `self` is less than or equal to `other`.
+    #[inline(always)]
+    fn num_le(self, other: Rhs) -> bool {
+        matches!(self.num_cmp(other), Some(Ordering::Less | Ordering::Equal))
+    }
+
+    /// Returns whether `self` is greater than `other`.
+    #[inline(always)]
+    fn num_gt(self, other: Rhs) -> bool {
+        self.num_cmp(other) == Some(Ordering::Greater)
+    }
+
+    /// Returns whether `self` is greater than or equal to `other`.
+    #[inline(always)]
+    fn num_ge(self, other: Rhs) -> bool {
+        matches!(
+            self.num_cmp(other),
+            Some(Ordering::Greater | Ordering::Equal)
+        )
+    }
+}
+
+mod private {
+    /// The exact value of a primitive number.
+    #[derive(Clone, Copy)]
+    pub enum Value {
+        /// An integer as a sign (`true` if negative) and a magnitude.
+        Int(bool, u128),
+        /// A float, which can represent exactly all values of the
+        /// supported float types.
+        Float(f64),
+    }
+
+    /// Sealed trait for the types comparable with
+    /// [`NumCmp`](super::NumCmp).
+    pub trait ExactValue: Copy {
+        fn exact_value(self) -> Value;
+    }
+}
+
+use private::{ExactValue, Value};
+
+/// Compares the magnitude `mag` of an integer with a non-negative
+/// (possibly negative zero), non-NaN float.
+#[inline(always)]
+fn cmp_magnitude(mag: u128, float: f64) -> Ordering {
+    // 2^128, the smallest float larger than all u128
+    if float >= 340282366920938463463374607431768211456.0 {
+        return Ordering::Less;
+    }
+    // The integer part of a float is representable, so the
+    // difference is exact
+    let int_part = float as u128;
+    mag.cmp(&int_part).then(if float - int_part as f64 > 0.0 {
+        Ordering::Less
+    } else {
+        Ordering::Equal
+    })
+}
+
+/// Compares an integer with sign `neg` and magnitude `mag` with a float.
+#[inline(always)]
+fn cmp_int_float(neg: bool, mag: u128, float: f64) -> Option<Ordering> {
+    if float.is_nan() {
+        None
+    } else if neg {
+        if float >= 0.0 {
+            Some(Ordering::Less)
+        } else {
+            Some(cmp_magnitude(mag, -float).reverse())
+        }
+    } else if float < 0.0 {
+        Some(Ordering::Greater)
+    } else {
+        Some(cmp_magnitude(mag, float))
+    }
+}
+
+impl<A: ExactValue, B: ExactValue> NumCmp<B> for A {
+    #[inline(always)]
+    fn num_cmp(self, other: B) -> Option<Ordering> {
+        match (self.exact_value(), other.exact_value()) {
+            (Value::Int(a_neg, a_mag), Value::Int(b_neg, b_mag)) => Some(match (a_neg, b_neg) {
+                (false, false) => a_mag.cmp(&b_mag),
+                (true, true) => b_mag.cmp(&a_mag),
+                (true, false) => Ordering::Less,
+                (false, true) => Ordering::Greater,
+            }),
+            (Value::Int(neg, mag), Value::Float(float)) => cmp_int_float(neg, mag, float),
+            (Value::Float(float), Value::Int(neg, mag)) => {
+                cmp_int_float(neg, mag, float).map(Ordering::reverse)
+            }
+            (Value::Float(a), Value::Float(b)) => a.partial_cmp(&b),
+        }
+    }
+}
+
+macro_rules! impl_exact_value_unsigned {
+    ($($ty:ty),*) => {$(
+impl ExactValue for $ty {
+    #[inline(always)]
+    fn exact_value(self) -> Value {
+        Value::Int(false, self as u128)
+    }
+}
+    )*};
+}
+
+macro_rules! impl_exact_value_signed {
+    ($($ty:ty),*) => {$(
+impl ExactValue for $ty {
+    #[inline(always)]
+    fn exact_value(self) -> Value {
+        Value::Int(self < 0, self.unsigned_abs() as u128)
+    }
+}
+    )*};
+}
+
+impl_exact_value_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_exact_value_signed!(i8, i16, i32, i64, i128, isize);
+
+impl ExactValue for f32 {
+    #[inline(always)]
+    fn exact_value(self) -> Value {
+        Value::Float(self as f64)
+    }
+}
+
+impl ExactValue for f64 {
+    #[inline(always)]
+    fn exact_value(self) -> Value {
+        Value::Float(self)
+    }
+}
+
+#[cfg(feature = "half")]
+mod half_impl {
+    use super::*;
+
+    impl ExactValue for half::f16 {
+        #[inline(always)]
+        fn exact_value(self) -> Value {
+            Value::Float(self.to_f64())
+        }
+    }
+
+    impl ExactValue for half::bf16 {
+        #[inline(always)]
+        fn exact_value(self) -> Value {
+            Value::Float(self.to_f64())
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use crate::{Number, UpcastableFrom};
+
+/// Type-level numeric promotion: the smallest primitive type that can
+/// represent exactly all values of `Self` and of `Rhs`.
+///
+/// For example, the promotion of `u8` and `i8` is `i16`, the promotion of
+/// `u32` and `f32` is `f64`, and the promotion of `half::f16` and
+/// `half::bf16` is `f32`. There is no implementation for pairs that no
+/// primitive type can represent, such as `u64` and `f64` or `u128` and `i8`.
+/// Since the promotion of `usize` and `isize` depends on the pointer width,
+/// pairs involving them are implemented only on the widths on which the
+/// promoted type exists: for example, `usize` and `f64` have a promotion on
+/// 16- and 32-bit targets, but not on 64-bit targets.
+///
+/// The [`Output`](`Promote::Output`) type is upcastable from both types, so
+/// [`promote`](`Promote::promote`) is lossless. The sum and the product
+/// computed by [`promote_add`](`Promote::promote_add`) and
+/// [`promote_mul`](`Promote::promote_mul`), instead, might not be
+/// representable in the promoted type: they overflow or round exactly like
+/// the native operators on it. For example, `u8::MAX.promote_mul(u16::MAX)`
+/// overflows `u16`, and `200_u8.promote_add(100_u8)` overflows `u8`.
+///
+/// # Examples
+///
+/// The accumulator type of a generic dot product can be inferred from the
+/// type of the elements; as with a native accumulator, the caller must make
+/// sure that the products and their sum fit in the promoted type:
+///
+/// ```rust
+/// use common_traits::*;
+///
+/// pub fn dot_product<A: Promote<B> + Copy, B: Copy>(a: &[A], b: &[B]) -> A::Output {
+///     assert_eq!(a.len(), b.len());
+///     let mut accum = A::Output::ZERO;
+///     for i in 0..a.len() {
+///         accum += a[i].promote_mul(b[i]);
+///     }
+///     accum
+/// }
+///
+/// let x: [u8; 3] = [1, 2, 3];
+/// let w: [i8; 3] = [-3, 2, 1];
+/// let res: i16 = dot_product(&x, &w);
+/// assert_eq!(res, 4);
+/// ```
+pub trait Promote<Rhs = Self>: Sized {
+    /// The smallest type that can represent all values of `Self` and `Rhs`.
+    type Output: Number + UpcastableFrom<Self> + UpcastableFrom<Rhs>;
+
+    /// Upcasts `self` to the promoted type.
+    #[inline(always)]
+    fn promote(self) -> Self::Output {
+        Self::Output::upcast_from(self)
+    }
+
+    /// Adds `self` and `rhs` in the promoted type, with the overflow and
+    /// rounding behavior of its `+` operator.
+    #[inline(always)]
+    fn promote_add(self, rhs: Rhs) -> Self::Output {
+        Self::Output::upcast_from(self) + Self::Output::upcast_from(rhs)
+    }
+
+    /// Multiplies `self` and `rhs` in the promoted type, with the overflow
+    /// and rounding behavior of its `*` operator.
+    #[inline(always)]
+    fn promote_mul(self, rhs: Rhs) -> Self::Output {
+        Self::Output::upcast_from(self) * Self::Output::upcast_from(rhs)
+    }
+}
+
+macro_rules! impl_promote {
+    ($($ty:ty),*) => {$(
+impl Promote for $ty {
+    type Output = $ty;
+}
+    )*};
+    ($($a:ty, $b:ty => $out:ty;)*) => {$(
+impl Promote<$b> for $a {
+    type Output = $out;
+}
+impl Promote<$a> for $b {
+    type Output = $out;
+}
+    )*};
+}
+
+impl_promote!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+impl_promote!(
+    u8, u16 => u16;
+    u8, u32 => u32;
+    u8, u64 => u64;
+    u8, u128 => u128;
+    u16, u32 => u32;
+    u16, u64 => u64;
+    u16, u128 => u128;
+    u32, u64 => u64;
+    u32, u128 => u128;
+    u64, u128 => u128;
+
+    i8, i16 => i16;
+    i8, i32 => i32;
+    i8, i64 => i64;
+    i8, i128 => i128;
+    i16, i32 => i32;
+    i16, i64 => i64;
+    i16, i128 => i128;
+    i32, i64 => i64;
+    i32, i128 => i128;
+    i64, i128 => i128;
+
+    u8, i8 => i16;
+    u8, i16 => i16;
+    u8, i32 => i32;
+    u8, i64 => i64;
+    u8, i128 => i128;
+    u16, i8 => i32;
+    u16, i16 => i32;
+    u16, i32 => i32;
+    u16, i64 => i64;
+    u16, i128 => i128;
+    u32, i8 => i64;
+    u32, i16 => i64;
+    u32, i32 => i64;
+    u32, i64 => i64;
+    u32, i128 => i128;
+    u64, i8 => i128;
+    u64, i16 => i128;
+    u64, i32 => i128;
+    u64, i64 => i128;
+    u64, i128 => i128;
+
+    f32, f64 => f64;
+    u8, f32 => f32;
+    u16, f32 => f32;
+    i8, f32 => f32;
+    i16, f32 => f32;
+    u32, f32 => f64;
+    i32, f32 => f64;
+    u8, f64 => f64;
+    u16, f64 => f64;
+    u32, f64 => f64;
+    i8, f64 => f64;
+    i16, f64 => f64;
+    i32, f64 => f64;
+);
+
+#[cfg(any(
+    target_pointer_width = "16",
+    target_pointer_width = "32",
+    target_pointer_width = "64",
+))]
+impl_promote!(
+    usize, u8 => usize;
+    usize, u16 => usize;
+    usize, u64 => u64;
+    usize, u128 => u128;
+    usize, i128 => i128;
+    isize, i8 => isize;
+    isize, i16 => isize;
+    isize, i64 => i64;
+    isize, i128 => i128;
+    isize, u8 => isize;
+    isize, u64 => i128;
+);
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64",))]
+impl_promote!(
+    usize, u32 => usize;
+    isize, i32 => isize;
+    isize, u16 => isize;
+);
+
+#[cfg(target_pointer_width = "16")]
+impl_promote!(
+    usize, u32 => u32;
+    usize, i8 => i32;
+    usize, i16 => i32;
+    usize, i32 => i32;
+    usize, i64 => i64;
+    usize, isize => i32;
+    isize, i32 => i32;
+    isize, u16 => i32;
+    isize, u32 => i64;
+    usize, f32 => f32;
+    usize, f64 => f64;
+    isize, f32 => f32;
+    isize, f64 => f64;
+);
+
+#[cfg(target_pointer_width = "32")]
+impl_promote!(
+    usize, i8 => i64;
+    usize, i16 => i64;
+    usize, i32 => i64;
+    usize, i64 => i64;
+    usize, isize => i64;
+    isize, u32 => i64;
+    usize, f32 => f64;
+    usize, f64 => f64;
+    isize, f32 => f64;
+    isize, f64 => f64;
+);
+
+#[cfg(target_pointer_width = "64")]
+impl_promote!(
+    usize, i8 => i128;
+    usize, i16 => i128;
+    usize, i32 => i128;
+    usize, i64 => i128;
+    usize, isize => i128;
+    isize, u32 => isize;
+);
+
+#[cfg(feature = "half")]
+mod half_impl {
+    use super::*;
+    use half::{bf16, f16};
+
+    impl_promote!(f16, bf16);
+
+    impl_promote!(
+        f16, bf16 => f32;
+        f16, f32 => f32;
+        f16, f64 => f64;
+        bf16, f32 => f32;
+        bf16, f64 => f64;
+        u8, f16 => f16;
+        i8, f16 => f16;
+        u16, f16 => f32;
+        i16, f16 => f32;
+        u32, f16 => f64;
+        i32, f16 => f64;
+        u8, bf16 => bf16;
+        i8, bf16 => bf16;
+        u16, bf16 => f32;
+        i16, bf16 => f32;
+        u32, bf16 => f64;
+        i32, bf16 => f64;
+    );
+}
@@ -1,9 +1,9 @@
-use core::sync::atomic::{
+use crate::sync::{
     AtomicBool, AtomicI8, AtomicI16, AtomicI32, AtomicI64, AtomicIsize, AtomicU8, AtomicU16,
     AtomicU32, AtomicU64, AtomicUsize,
 };
 
-use crate::{AtomicF32, AtomicF64, IntoAtomic};
+use crate::{AtomicF32, AtomicF64, AtomicI128, AtomicU128, IntoAtomic};
 
 /// Unsafe marker trait for types whose atomic version has the same memory layout
 /// and semantics.
@@ -28,12 +28,14 @@ unsafe impl SameAs<AtomicU16> for u16 {}
 unsafe impl SameAs<AtomicU32> for u32 {}
 unsafe impl SameAs<AtomicU64> for u64 {}
 unsafe impl SameAs<AtomicUsize> for usize {}
+unsafe impl SameAs<AtomicU128> for u128 {}
 
 unsafe impl SameAs<AtomicI8> for i8 {}
 unsafe impl SameAs<AtomicI16> for i16 {}
 unsafe impl SameAs<AtomicI32> for i32 {}
 unsafe impl SameAs<AtomicI64> for i64 {}
 unsafe impl SameAs<AtomicIsize> for isize {}
+unsafe impl SameAs<AtomicI128> for i128 {}
 
 unsafe impl SameAs<AtomicBool> for bool {}
 
@@ -0,0 +1,427 @@
+use crate::atomic::{check_failure_ordering, check_load_ordering, check_store_ordering};
+use crate::{Atomic, False, IntoAtomic, IsAtomic, SameAs, True};
+use core::cell::UnsafeCell;
+use core::mem::MaybeUninit;
+use core::sync::atomic::{AtomicUsize, Ordering, fence};
+
+const STRIPES: usize = 64;
+
+/// A sequence counter, padded to avoid false sharing.
+#[repr(align(64))]
+struct Stripe(AtomicUsize);
+
+/// Sequence counters shared by all instances of [`SeqLockAtomic`].
+///
+/// A counter is odd while a writer is modifying one of the values it
+/// protects.
+static SEQUENCES: [Stripe; STRIPES] = [const { Stripe(AtomicUsize::new(0)) }; STRIPES];
+
+/// An atomic value of arbitrary size protected by a sequence lock.
+///
+/// Values too large for a native atomic, such as `[f64; 4]` or records of a
+/// few words, can be stored in a `SeqLockAtomic`, which implements
+/// [`Atomic`], so code generic on [`Atomic`] can handle them without changes.
+/// Readers are lock-free: they copy the value optimistically and retry if a
+/// writer modified it in the meanwhile. Writers are serialized.
+///
+/// The sequence counters are not stored in the atomic, but in a fixed
+/// number of cache-padded counters selected by the address of the atomic,
+/// so a `SeqLockAtomic<T>` has the same layout as `T`, as required by
+/// [`SameAs`]; the price is that writers on atomics sharing a counter are
+/// serialized, too.
+///
+/// [`compare_exchange`](Atomic::compare_exchange) compares values using
+/// [`PartialEq`], whereas [`fetch_update`](Atomic::fetch_update) checks that
+/// no write happened between the load and the store, so it does not need
+/// comparisons and never calls the closure while holding the lock.
+///
+/// Loads have always at least acquire semantics, and stores at least
+/// release semantics; operations with ordering
+/// [`SeqCst`](Ordering::SeqCst) are additionally preceded by a sequentially
+/// consistent fence.
+///
+/// Arrays of non-atomic types implement [`IntoAtomic`] using this type, and
+/// the macro [`impl_into_seq_lock_atomic`](crate::impl_into_seq_lock_atomic)
+/// implements [`IntoAtomic`] for user-defined types.
+///
+/// # Examples
+///
+/// ```rust
+/// use common_traits::{Atomic, IntoAtomic, SeqLockAtomic};
+/// use core::sync::atomic::Ordering;
+///
+/// // A bounding box
+/// let bbox: SeqLockAtomic<[f64; 4]> = [0.0, 0.0, 1.0, 1.0].to_atomic();
+/// bbox.fetch_update(Ordering::AcqRel, Ordering::Acquire, |[x0, y0, x1, y1]| {
+///     Some([x0.min(-1.0), y0.min(2.0), x1.max(-1.0), y1.max(2.0)])
+/// })
+/// .unwrap();
+/// assert_eq!(bbox.load(Ordering::Acquire), [-1.0, 0.0, 1.0, 2.0]);
+/// ```
+#[repr(transparent)]
+pub struct SeqLockAtomic<T>(UnsafeCell<T>);
+
+// SAFETY: all accesses through a shared reference are protected by the
+// sequence lock, and values are moved between threads
+unsafe impl<T: Copy + Send> Sync for SeqLockAtomic<T> {}
+
+impl<T> IsAtomic for SeqLockAtomic<T> {
+    type Atomic = True;
+}
+
+impl<T: Copy> SeqLockAtomic<T> {
+    /// Returns the sequence counter of this atomic.
+    #[inline(always)]
+    fn sequence(&self) -> &'static AtomicUsize {
+        &SEQUENCES[(self.0.get() as usize >> 3) % STRIPES].0
+    }
+
+    /// Reads the value optimistically, returning it together with the
+    /// (even) value of the sequence counter at the time of the read.
+    #[inline]
+    fn read(&self) -> (T, usize) {
+        let sequence = self.sequence();
+        loop {
+            let start = sequence.load(Ordering::Acquire);
+            if start & 1 == 0 {
+                // The value might be torn, so we do not assume it is
+                // initialized until the sequence counter is validated
+                let value =
+                    unsafe { core::ptr::read_volatile(self.0.get() as *const MaybeUninit<T>) };
+                fence(Ordering::Acquire);
+                if sequence.load(Ordering::Relaxed) == start {
+                    return (unsafe { value.assume_init() }, start);
+                }
+            }
+            core::hint::spin_loop();
+        }
+    }
+
+    /// Tries to lock the atomic assuming the sequence counter has value
+    /// `start`, which must be even.
+    #[inline(always)]
+    fn try_lock(&self, start: usize) -> bool {
+        let locked = self
+            .sequence()
+            .compare_exchange_weak(start, start + 1, Ordering::Acquire, Ordering::Relaxed)
+            .is_ok();
+        if locked {
+            fence(Ordering::Release);
+        }
+        locked
+    }
+
+    /// Locks the atomic, returning the (even) value of the sequence counter
+    /// before locking.
+    #[inline]
+    fn lock(&self) -> usize {
+        loop {
+            let start = self.sequence().load(Ordering::Relaxed);
+            if start & 1 == 0 && self.try_lock(start) {
+                return start;
+            }
+            core::hint::spin_loop();
+        }
+    }
+
+    /// Writes a value to the locked atomic and unlocks it.
+    #[inline(always)]
+    fn write_and_unlock(&self, value: T, start: usize) {
+        unsafe { core::ptr::write_volatile(self.0.get(), value) };
+        self.sequence()
+            .store(start.wrapping_add(2), Ordering::Release);
+    }
+}
+
+#[inline(always)]
+fn seq_cst_fence(order: Ordering) {
+    if order == Ordering::SeqCst {
+        fence(Ordering::SeqCst);
+    }
+}
+
+impl<T> Atomic for SeqLockAtomic<T>
+where
+    T: IntoAtomic<AtomicType = Self> + SameAs<Self> + Copy + PartialEq,
+{
+    type NonAtomicType = T;
+
+    #[inline(always)]
+    fn new(value: T) -> Self {
+        Self(UnsafeCell::new(value))
+    }
+
+    #[inline]
+    fn load(&self, order: Ordering) -> T {
+        check_load_ordering(order);
+        seq_cst_fence(order);
+        self.read().0
+    }
+
+    #[inline]
+    fn store(&self, value: T, order: Ordering) {
+        check_store_ordering(order);
+        seq_cst_fence(order);
+        let start = self.lock();
+        self.write_and_unlock(value, start);
+    }
+
+    #[inline(always)]
+    fn get_mut(&mut self) -> &mut T {
+        self.0.get_mut()
+    }
+
+    #[inline(always)]
+    fn into_inner(self) -> T {
+        self.0.into_inner()
+    }
+
+    #[inline(always)]
+    fn into_non_atomic_array<const N: usize>(data: [Self; N]) -> [T; N] {
+        data.map(Self::into_inner)
+    }
+
+    #[inline(always)]
+    fn from_non_atomic_array<const N: usize>(data: [T; N]) -> [Self; N] {
+        data.map(Self::new)
+    }
+
+    #[inline(always)]
+    fn get_mut_slice(this: &mut [Self]) -> &mut [T] {
+        unsafe { core::slice::from_raw_parts_mut(this.as_mut_ptr() as *mut T, this.len()) }
+    }
+
+    #[inline(always)]
+    fn from_mut_slice(this: &mut [T]) -> &mut [Self] {
+        unsafe { core::slice::from_raw_parts_mut(this.as_mut_ptr() as *mut Self, this.len()) }
+    }
+
+    #[inline(always)]
+    fn get_mut_array<const N: usize>(this: &mut [Self; N]) -> &mut [T; N] {
+        unsafe { &mut *(this as *mut [Self; N] as *mut [T; N]) }
+    }
+
+    #[inline(always)]
+    fn from_mut_array<const N: usize>(this: &mut [T; N]) -> &mut [Self; N] {
+        unsafe { &mut *(this as *mut [T; N] as *mut [Self; N]) }
+    }
+
+    #[inline]
+    fn compare_exchange(
+        &self,
+        current: T,
+        new: T,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<T, T> {
+        check_failure_ordering(failure);
+        seq_cst_fence(success);
+        loop {
+            let (prev, start) = self.read();
+            if prev != current {
+                return Err(prev);
+            }
+            if self.try_lock(start) {
+                self.write_and_unlock(new, start);
+                return Ok(prev);
+            }
+        }
+    }
+
+    #[inline]
+    fn compare_exchange_weak(
+        &self,
+        current: T,
+        new: T,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<T, T> {
+        self.compare_exchange(current, new, success, failure)
+    }
+
+    #[inline]
+    fn swap(&self, new: T, order: Ordering) -> T {
+        seq_cst_fence(order);
+        let start = self.lock();
+        // No other writer can modify the value
+        let prev = unsafe { core::ptr::read_volatile(self.0.get()) };
+        self.write_and_unlock(new, start);
+        prev
+    }
+
+    #[inline]
+    fn fetch_update<F>(&self, set_order: Ordering, fetch_order: Ordering, mut f: F) -> Result<T, T>
+    where
+        F: FnMut(T) -> Option<T>,
+    {
+        check_failure_ordering(fetch_order);
+        seq_cst_fence(set_order);
+        loop {
+            let (prev, start) = self.read();
+            let new = f(prev).ok_or(prev)?;
+            // The lock succeeds only if nobody wrote since the read
+            if self.try_lock(start) {
+                self.write_and_unlock(new, start);
+                return Ok(prev);
+            }
+        }
+    }
+}
+
+// SAFETY: SeqLockAtomic<T> is a transparent wrapper around UnsafeCell<T>
+unsafe impl<T: IntoAtomic<AtomicType = SeqLockAtomic<T>>> SameAs<SeqLockAtomic<T>> for T {}
+
+impl<T> Default for SeqLockAtomic<T>
+where
+    T: IntoAtomic<AtomicType = Self> + SameAs<Self> + Copy + PartialEq + Default,
+{
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> From<T> for SeqLockAtomic<T>
+where
+    T: IntoAtomic<AtomicType = Self> + SameAs<Self> + Copy + PartialEq,
+{
+    #[inline(always)]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> core::fmt::Debug for SeqLockAtomic<T>
+where
+    T: IntoAtomic<AtomicType = Self> + SameAs<Self> + Copy + PartialEq + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
+
+impl<T: IsAtomic<Atomic = False>, const N: usize> IsAtomic for [T; N] {
+    type Atomic = False;
+}
+
+impl<T, const N: usize> IntoAtomic for [T; N]
+where
+    T: IsAtomic<Atomic = False> + Copy + PartialEq + Send + Sync,
+{
+    type AtomicType = SeqLockAtomic<[T; N]>;
+
+    #[inline(always)]
+    fn to_atomic(self) -> Self::AtomicType {
+        SeqLockAtomic::new(self)
+    }
+
+    #[inline(always)]
+    fn into_atomic_array<const M: usize>(data: [Self; M]) -> [Self::AtomicType; M] {
+        Self::AtomicType::from_non_atomic_array(data)
+    }
+
+    #[inline(always)]
+    fn from_atomic_array<const M: usize>(data: [Self::AtomicType; M]) -> [Self; M] {
+        Self::AtomicType::into_non_atomic_array(data)
+    }
+
+    #[inline(always)]
+    fn get_mut_slice(this: &mut [Self::AtomicType]) -> &mut [Self] {
+        Self::AtomicType::get_mut_slice(this)
+    }
+
+    #[inline(always)]
+    fn from_mut_slice(this: &mut [Self]) -> &mut [Self::AtomicType] {
+        Self::AtomicType::from_mut_slice(this)
+    }
+
+    #[inline(always)]
+    fn get_mut_array<const M: usize>(this: &mut [Self::AtomicType; M]) -> &mut [Self; M] {
+        Self::AtomicType::get_mut_array(this)
+    }
+
+    #[inline(always)]
+    fn from_mut_array<const M: usize>(this: &mut [Self; M]) -> &mut [Self::AtomicType; M] {
+        Self::AtomicType::from_mut_array(this)
+    }
+}
+
+/// Implements [`IsAtomic`](crate::IsAtomic) and
+/// [`IntoAtomic`](crate::IntoAtomic) for the given types, using
+/// [`SeqLockAtomic`](crate::SeqLockAtomic) as atomic type.
+///
+/// The types must implement [`Copy`], [`PartialEq`], [`Send`] and [`Sync`].
+///
+/// # Examples
+///
+/// ```rust
+/// use common_traits::{impl_into_seq_lock_atomic, Atomic, IntoAtomic};
+/// use core::sync::atomic::Ordering;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// struct Record {
+///     id: u64,
+///     score: f64,
+///     count: u32,
+/// }
+///
+/// impl_into_seq_lock_atomic!(Record);
+///
+/// let record = Record { id: 1, score: 0.5, count: 0 }.to_atomic();
+/// record
+///     .fetch_update(Ordering::AcqRel, Ordering::Acquire, |r| {
+///         Some(Record { score: r.score + 1.0, count: r.count + 1, ..r })
+///     })
+///     .unwrap();
+/// assert_eq!(
+///     record.load(Ordering::Acquire),
+///     Record { id: 1, score: 1.5, count: 1 }
+/// );
+/// ```
+#[macro_export]
+macro_rules! impl_into_seq_lock_atomic {
+    ($($ty:ty),* $(,)?) => {$(
+        impl $crate::IsAtomic for $ty {
+            type Atomic = $crate::False;
+        }
+
+        impl $crate::IntoAtomic for $ty {
+            type AtomicType = $crate::SeqLockAtomic<$ty>;
+
+            #[inline(always)]
+            fn to_atomic(self) -> Self::AtomicType {
+                <Self::AtomicType as $crate::Atomic>::new(self)
+            }
+
+            #[inline(always)]
+            fn into_atomic_array<const N: usize>(data: [Self; N]) -> [Self::AtomicType; N] {
+                <Self::AtomicType as $crate::Atomic>::from_non_atomic_array(data)
+            }
+
+            #[inline(always)]
+            fn from_atomic_array<const N: usize>(data: [Self::AtomicType; N]) -> [Self; N] {
+                <Self::AtomicType as $crate::Atomic>::into_non_atomic_array(data)
+            }
+
+            #[inline(always)]
+            fn get_mut_slice(this: &mut [Self::AtomicType]) -> &mut [Self] {
+                <Self::AtomicType as $crate::Atomic>::get_mut_slice(this)
+            }
+
+            #[inline(always)]
+            fn from_mut_slice(this: &mut [Self]) -> &mut [Self::AtomicType] {
+                <Self::AtomicType as $crate::Atomic>::from_mut_slice(this)
+            }
+
+            #[inline(always)]
+            fn get_mut_array<const N: usize>(this: &mut [Self::AtomicType; N]) -> &mut [Self; N] {
+                <Self::AtomicType as $crate::Atomic>::get_mut_array(this)
+            }
+
+            #[inline(always)]
+            fn from_mut_array<const N: usize>(this: &mut [Self; N]) -> &mut [Self::AtomicType; N] {
+                <Self::AtomicType as $crate::Atomic>::from_mut_array(this)
+            }
+        }
+    )*};
+}
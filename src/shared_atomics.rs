@@ -0,0 +1,213 @@
+use crate::{Atomic, AtomicNumber, Number};
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+use std::fs::File;
+use std::io;
+use std::os::fd::AsRawFd;
+
+/// A slice of atomic values placed over a memory mapping shared among
+/// processes.
+///
+/// The mapping is either anonymous, and thus shared with the child processes
+/// created by `fork`, or backed by a file, and thus shared with every process
+/// mapping the same file. The slice is unmapped on drop.
+///
+/// Values in the slice are coherent among processes only if the atomic type
+/// is lock free: the fallback locks of [`AtomicU128`](crate::AtomicU128) and
+/// the sequence numbers of [`SeqLockAtomic`](crate::SeqLockAtomic) are local
+/// to a process, and so is the [`AtomicWait`](crate::AtomicWait) notification
+/// mechanism.
+///
+/// # Examples
+///
+/// ```rust
+/// use common_traits::{Atomic, AtomicF64, AtomicNumber, SharedAtomics};
+/// use core::sync::atomic::Ordering;
+///
+/// let counters = SharedAtomics::<AtomicF64>::anonymous(4).unwrap();
+/// counters[1].fetch_add(0.5, Ordering::Relaxed);
+/// assert_eq!(counters[1].load(Ordering::Relaxed), 0.5);
+/// assert_eq!(counters[2].load(Ordering::Relaxed), 0.0);
+/// ```
+pub struct SharedAtomics<A: Atomic> {
+    ptr: NonNull<A>,
+    len: usize,
+    _marker: PhantomData<A>,
+}
+
+// SAFETY: the mapping is owned by the structure, and atomic types are Send and Sync
+unsafe impl<A: Atomic> Send for SharedAtomics<A> {}
+// SAFETY: as above
+unsafe impl<A: Atomic> Sync for SharedAtomics<A> {}
+
+impl<A: AtomicNumber> SharedAtomics<A>
+where
+    A::NonAtomicType: Number,
+{
+    /// Creates an anonymous shared mapping of `len` atomic values initialized
+    /// to zero.
+    ///
+    /// The mapping is shared with the child processes created by `fork`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput)
+    /// if the size in bytes of the mapping overflows, or the error of the
+    /// `mmap` system call.
+    pub fn anonymous(len: usize) -> io::Result<Self> {
+        let size = Self::size(len)?;
+        // SAFETY: an anonymous mapping is filled with zeros, which is a valid
+        // representation for all numbers
+        unsafe { Self::map(size, libc::MAP_SHARED | libc::MAP_ANONYMOUS, -1, 0) }
+    }
+}
+
+impl<A: Atomic> SharedAtomics<A> {
+    /// Maps `len` atomic values from `file`, starting at byte `offset`.
+    ///
+    /// The file must be open for reading and writing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput)
+    /// if `offset` is not a multiple of the page size, or if the size in bytes
+    /// of the mapping overflows or exceeds the length of the file (accessing
+    /// a mapping past the end of a file raises a `SIGBUS` signal). Otherwise,
+    /// returns the error of the `mmap` system call.
+    ///
+    /// # Safety
+    ///
+    /// - The content of the file must be a valid representation of `len`
+    ///   values of type [`A::NonAtomicType`](Atomic::NonAtomicType).
+    /// - While the mapping exists, the file must not be truncated, and its
+    ///   content must be modified only through atomic operations, by this or
+    ///   other processes.
+    pub unsafe fn from_file(file: &File, offset: u64, len: usize) -> io::Result<Self> {
+        let size = Self::size(len)?;
+        // SAFETY: sysconf has no preconditions
+        let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as u64;
+        if offset % page_size != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("offset {offset} is not a multiple of the page size {page_size}"),
+            ));
+        }
+        let file_len = file.metadata()?.len();
+        if offset
+            .checked_add(size as u64)
+            .is_none_or(|end| end > file_len)
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{size} bytes at offset {offset} exceed the file length {file_len}"),
+            ));
+        }
+        let offset = libc::off_t::try_from(offset)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+        // SAFETY: the caller guarantees that the content is valid
+        unsafe { Self::map(size, libc::MAP_SHARED, file.as_raw_fd(), offset) }
+    }
+
+    /// Returns the size in bytes of a mapping of `len` atomic values.
+    fn size(len: usize) -> io::Result<usize> {
+        #[cfg(loom)]
+        crate::sync::check_layout();
+        core::mem::size_of::<A>().checked_mul(len).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("a mapping of {len} atomic values is too large"),
+            )
+        })
+    }
+
+    /// Maps `size` bytes, checking the alignment of the result.
+    ///
+    /// # Safety
+    ///
+    /// The mapped memory must be a valid representation of
+    /// `size / size_of::<A>()` values.
+    unsafe fn map(
+        size: usize,
+        flags: libc::c_int,
+        fd: libc::c_int,
+        offset: libc::off_t,
+    ) -> io::Result<Self> {
+        let len = size / core::mem::size_of::<A>();
+        if size == 0 {
+            // mmap rejects empty mappings
+            return Ok(Self {
+                ptr: NonNull::dangling(),
+                len,
+                _marker: PhantomData,
+            });
+        }
+        // SAFETY: we are creating a new mapping, so no existing memory is affected
+        let ptr = unsafe {
+            libc::mmap(
+                core::ptr::null_mut(),
+                size,
+                libc::PROT_READ | libc::PROT_WRITE,
+                flags,
+                fd,
+                offset,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        let result = Self {
+            // SAFETY: mmap does not return a null pointer on success
+            ptr: unsafe { NonNull::new_unchecked(ptr as *mut A) },
+            len,
+            _marker: PhantomData,
+        };
+        // Mappings are page aligned, so this can fail only for atomic types
+        // with an alignment larger than a page
+        if !result.ptr.as_ptr().is_aligned() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "the mapping is not aligned to {} bytes",
+                    core::mem::align_of::<A>()
+                ),
+            ));
+        }
+        Ok(result)
+    }
+}
+
+impl<A: Atomic> core::ops::Deref for SharedAtomics<A> {
+    type Target = [A];
+
+    #[inline(always)]
+    fn deref(&self) -> &[A] {
+        // SAFETY: the mapping contains len valid values and lives as long as self
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<A: Atomic> AsRef<[A]> for SharedAtomics<A> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[A] {
+        self
+    }
+}
+
+impl<A: Atomic> Drop for SharedAtomics<A> {
+    fn drop(&mut self) {
+        let size = core::mem::size_of_val::<[A]>(self);
+        if size != 0 {
+            // SAFETY: the mapping was created by map with this size
+            unsafe { libc::munmap(self.ptr.as_ptr() as *mut libc::c_void, size) };
+        }
+    }
+}
+
+impl<A: Atomic> core::fmt::Debug for SharedAtomics<A>
+where
+    A: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&**self, f)
+    }
+}
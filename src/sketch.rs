@@ -0,0 +1,789 @@
+use crate::atomic::load_ordering;
+use crate::atomic_rng::mix;
+use crate::sync::{AtomicU8, AtomicU64};
+use crate::{
+    AsBytes, Atomic, AtomicUnsignedInt, FastRange, FiniteRangeNumber, FromBytes, Hash, Hasher,
+    Number, SeedableHasher, To, ToBytes, UnsignedInt,
+};
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use anyhow::{Result, bail};
+use core::marker::PhantomData;
+use core::sync::atomic::Ordering;
+
+/// Hashes `item` with a hasher built from `seed`, and finalizes the result.
+///
+/// The finalization makes every bit of the result depend on every bit of the
+/// hash, so hashers with short or weak outputs can be used.
+#[inline(always)]
+fn hash<H, T: Hash + ?Sized>(seed: &H::Seed, item: &T) -> u64
+where
+    H: Hasher + SeedableHasher,
+    H::Result: To<u64>,
+    H::Seed: Clone,
+{
+    let mut hasher = H::new(seed.clone());
+    item.hash(&mut hasher);
+    mix(hasher.finish().to())
+}
+
+/// Returns the `i`-th of a sequence of pseudorandom indices in [0 . . `len`)
+/// derived from `hash` by double hashing.
+#[inline(always)]
+fn probe(hash: u64, i: usize, len: u64) -> usize {
+    // odd, and thus coprime with the 2⁶⁴ possible hashes
+    let step = mix(hash) | 1;
+    hash.wrapping_add(step.wrapping_mul(i as u64))
+        .fast_range(len) as usize
+}
+
+/// Returns `len` atomic values initialized to zero.
+fn zeros<A: Atomic>(len: usize) -> Box<[A]>
+where
+    A::NonAtomicType: Number,
+{
+    (0..len).map(|_| A::new(A::NonAtomicType::ZERO)).collect()
+}
+
+/// Serializes atomic values in little-endian order.
+fn to_le_bytes<A: Atomic>(values: &[A], order: Ordering) -> Vec<u8>
+where
+    A::NonAtomicType: ToBytes,
+{
+    let mut bytes = Vec::with_capacity(values.len() * A::NonAtomicType::BYTES);
+    for value in values {
+        bytes.extend_from_slice(value.load(order).to_le_bytes().as_ref());
+    }
+    bytes
+}
+
+/// Deserializes `len` atomic values serialized by [`to_le_bytes`].
+fn from_le_bytes<A: Atomic>(len: usize, bytes: &[u8]) -> Result<Box<[A]>>
+where
+    A::NonAtomicType: FromBytes,
+{
+    let expected = len * A::NonAtomicType::BYTES;
+    if bytes.len() != expected {
+        bail!(
+            "expected {} bytes for {} values, got {}",
+            expected,
+            len,
+            bytes.len()
+        );
+    }
+    Ok(bytes
+        .chunks_exact(A::NonAtomicType::BYTES)
+        .map(|chunk| {
+            let mut value = <A::NonAtomicType as AsBytes>::Bytes::default();
+            value.as_mut().copy_from_slice(chunk);
+            A::new(A::NonAtomicType::from_le_bytes(value))
+        })
+        .collect())
+}
+
+/// A Bloom filter whose bits are stored in [`AtomicUnsignedInt`] words (by
+/// default, [`AtomicU64`]), so that items can be inserted concurrently.
+///
+/// Items must implement the crate's [`Hash`] trait, and they are hashed with
+/// a [`Hasher`] of type `H` built from a seed; the `k` bits of an item are
+/// selected by double hashing and [`FastRange`].
+///
+/// Two filters can be [merged](BloomFilter::merge) if they have the same
+/// parameters and seed, and the bits of a filter can be serialized with
+/// [`ToBytes`] using [`to_le_bytes`](BloomFilter::to_le_bytes).
+///
+/// # Examples
+///
+/// ```rust
+/// use common_traits::{BloomFilter, Hasher, SeedableHasher};
+/// use core::sync::atomic::Ordering;
+///
+/// // FNV-1a
+/// struct Fnv(u64);
+///
+/// impl SeedableHasher for Fnv {
+///     type Seed = u64;
+///     fn new(seed: u64) -> Self {
+///         Self(0xCBF2_9CE4_8422_2325 ^ seed)
+///     }
+/// }
+///
+/// impl Hasher for Fnv {
+///     type Result = u64;
+///     fn finish(&self) -> u64 {
+///         self.0
+///     }
+///     fn write(&mut self, bytes: &[u8]) {
+///         for &byte in bytes {
+///             self.0 = (self.0 ^ byte as u64).wrapping_mul(0x100_0000_01B3);
+///         }
+///     }
+/// }
+///
+/// let filter = BloomFilter::<Fnv>::new(1 << 16, 5, 0);
+/// std::thread::scope(|s| {
+///     for t in 0..4_u64 {
+///         let filter = &filter;
+///         s.spawn(move || {
+///             for i in 0..1000 {
+///                 filter.insert(&(t * 1000 + i), Ordering::Relaxed);
+///             }
+///         });
+///     }
+/// });
+/// assert!((0..4000_u64).all(|i| filter.contains(&i, Ordering::Relaxed)));
+/// assert!(!filter.contains("missing", Ordering::Relaxed));
+/// ```
+pub struct BloomFilter<H: SeedableHasher, A = AtomicU64> {
+    seed: H::Seed,
+    num_bits: u64,
+    num_hashes: usize,
+    words: Box<[A]>,
+    _marker: PhantomData<fn() -> H>,
+}
+
+impl<H, A> BloomFilter<H, A>
+where
+    H: Hasher + SeedableHasher,
+    H::Result: To<u64>,
+    H::Seed: Clone + PartialEq,
+    A: AtomicUnsignedInt,
+    A::NonAtomicType: UnsignedInt + ToBytes + FromBytes,
+{
+    /// Creates a new empty filter with `num_bits` bits and `num_hashes` bits
+    /// per item, hashing items with a hasher built from `seed`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_bits` or `num_hashes` is zero.
+    pub fn new(num_bits: u64, num_hashes: usize, seed: H::Seed) -> Self {
+        assert!(num_bits > 0, "a Bloom filter needs at least one bit");
+        assert!(num_hashes > 0, "a Bloom filter needs at least one hash");
+        Self {
+            seed,
+            num_bits,
+            num_hashes,
+            words: zeros(Self::num_words(num_bits)),
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    fn num_words(num_bits: u64) -> usize {
+        num_bits.div_ceil(A::NonAtomicType::BITS as u64) as usize
+    }
+
+    /// Returns the number of bits.
+    #[inline(always)]
+    pub fn num_bits(&self) -> u64 {
+        self.num_bits
+    }
+
+    /// Returns the number of bits per item.
+    #[inline(always)]
+    pub fn num_hashes(&self) -> usize {
+        self.num_hashes
+    }
+
+    /// Inserts an item, returning whether all its bits were already set, that
+    /// is, whether the item was possibly already in the filter.
+    ///
+    /// The ordering has the same meaning as in
+    /// [`AtomicInteger::fetch_or`](crate::AtomicInteger::fetch_or), but it
+    /// applies to each word separately.
+    pub fn insert<T: Hash + ?Sized>(&self, item: &T, order: Ordering) -> bool {
+        let hash = hash::<H, T>(&self.seed, item);
+        let mut present = true;
+        for i in 0..self.num_hashes {
+            let bit = probe(hash, i, self.num_bits);
+            let mask = A::NonAtomicType::ONE << (bit % A::NonAtomicType::BITS);
+            let word = &self.words[bit / A::NonAtomicType::BITS];
+            present &= word.fetch_or(mask, order) & mask != A::NonAtomicType::ZERO;
+        }
+        present
+    }
+
+    /// Returns whether an item is possibly in the filter.
+    ///
+    /// There are no false negatives for items whose insertion happened
+    /// before the call.
+    pub fn contains<T: Hash + ?Sized>(&self, item: &T, order: Ordering) -> bool {
+        let hash = hash::<H, T>(&self.seed, item);
+        (0..self.num_hashes).all(|i| {
+            let bit = probe(hash, i, self.num_bits);
+            let mask = A::NonAtomicType::ONE << (bit % A::NonAtomicType::BITS);
+            self.words[bit / A::NonAtomicType::BITS].load(order) & mask != A::NonAtomicType::ZERO
+        })
+    }
+
+    /// Adds to this filter the items of another filter, computing the union
+    /// of their bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the filters have different parameters or seeds.
+    pub fn merge(&self, other: &Self, load_order: Ordering, or_order: Ordering) {
+        assert!(
+            self.num_bits == other.num_bits
+                && self.num_hashes == other.num_hashes
+                && self.seed == other.seed,
+            "cannot merge Bloom filters with different parameters or seeds"
+        );
+        for (word, other) in self.words.iter().zip(other.words.iter()) {
+            let bits = other.load(load_order);
+            if bits != A::NonAtomicType::ZERO {
+                word.fetch_or(bits, or_order);
+            }
+        }
+    }
+
+    /// Removes all items.
+    pub fn clear(&self, order: Ordering) {
+        for word in self.words.iter() {
+            word.store(A::NonAtomicType::ZERO, order);
+        }
+    }
+
+    /// Serializes the bits of the filter as a sequence of words in
+    /// little-endian order.
+    ///
+    /// The parameters and the seed are not serialized.
+    pub fn to_le_bytes(&self, order: Ordering) -> Vec<u8> {
+        to_le_bytes(&self.words, order)
+    }
+
+    /// Deserializes a filter serialized by
+    /// [`to_le_bytes`](BloomFilter::to_le_bytes), given its parameters and
+    /// seed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length of `bytes` does not match the
+    /// parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_bits` or `num_hashes` is zero.
+    pub fn from_le_bytes(
+        num_bits: u64,
+        num_hashes: usize,
+        seed: H::Seed,
+        bytes: &[u8],
+    ) -> Result<Self> {
+        let mut result = Self::new(num_bits, num_hashes, seed);
+        result.words = from_le_bytes(result.words.len(), bytes)?;
+        Ok(result)
+    }
+}
+
+impl<H: SeedableHasher, A> core::fmt::Debug for BloomFilter<H, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BloomFilter")
+            .field("num_bits", &self.num_bits)
+            .field("num_hashes", &self.num_hashes)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A counting Bloom filter whose counters are [`AtomicUnsignedInt`] values
+/// (by default, [`AtomicU8`]), so that items can be inserted and removed
+/// concurrently.
+///
+/// Items are hashed as in [`BloomFilter`]. Counters saturate at their maximum
+/// value, and saturated counters are never decremented, so removals never
+/// cause false negatives for items in the filter; removing items that were
+/// not inserted, however, may.
+///
+/// Two filters can be [merged](CountingBloomFilter::merge) if they have the
+/// same parameters and seed, and the counters of a filter can be serialized
+/// with [`ToBytes`] using [`to_le_bytes`](CountingBloomFilter::to_le_bytes).
+pub struct CountingBloomFilter<H: SeedableHasher, A = AtomicU8> {
+    seed: H::Seed,
+    num_hashes: usize,
+    counters: Box<[A]>,
+    _marker: PhantomData<fn() -> H>,
+}
+
+impl<H, A> CountingBloomFilter<H, A>
+where
+    H: Hasher + SeedableHasher,
+    H::Result: To<u64>,
+    H::Seed: Clone + PartialEq,
+    A: AtomicUnsignedInt,
+    A::NonAtomicType: UnsignedInt + FiniteRangeNumber + ToBytes + FromBytes,
+{
+    /// Creates a new empty filter with `num_counters` counters and
+    /// `num_hashes` counters per item, hashing items with a hasher built
+    /// from `seed`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_counters` or `num_hashes` is zero.
+    pub fn new(num_counters: usize, num_hashes: usize, seed: H::Seed) -> Self {
+        assert!(
+            num_counters > 0,
+            "a counting Bloom filter needs at least one counter"
+        );
+        assert!(
+            num_hashes > 0,
+            "a counting Bloom filter needs at least one hash"
+        );
+        Self {
+            seed,
+            num_hashes,
+            counters: zeros(num_counters),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of counters.
+    #[inline(always)]
+    pub fn num_counters(&self) -> usize {
+        self.counters.len()
+    }
+
+    /// Returns the number of counters per item.
+    #[inline(always)]
+    pub fn num_hashes(&self) -> usize {
+        self.num_hashes
+    }
+
+    #[inline(always)]
+    fn counters<'a, T: Hash + ?Sized>(&'a self, item: &T) -> impl Iterator<Item = &'a A> + 'a {
+        let hash = hash::<H, T>(&self.seed, item);
+        let len = self.counters.len() as u64;
+        (0..self.num_hashes).map(move |i| &self.counters[probe(hash, i, len)])
+    }
+
+    /// Inserts an item.
+    ///
+    /// The orderings have the same meaning as in [`Atomic::fetch_update`],
+    /// but they apply to each counter separately.
+    pub fn insert<T: Hash + ?Sized>(&self, item: &T, set_order: Ordering, fetch_order: Ordering) {
+        for counter in self.counters(item) {
+            let _ = counter.fetch_update(set_order, fetch_order, |count| {
+                if count == A::NonAtomicType::MAX {
+                    None
+                } else {
+                    Some(count + A::NonAtomicType::ONE)
+                }
+            });
+        }
+    }
+
+    /// Removes an item, which must have been inserted.
+    ///
+    /// The orderings have the same meaning as in [`insert`](Self::insert).
+    pub fn remove<T: Hash + ?Sized>(&self, item: &T, set_order: Ordering, fetch_order: Ordering) {
+        for counter in self.counters(item) {
+            let _ = counter.fetch_update(set_order, fetch_order, |count| {
+                // saturated counters might count more items than their value
+                if count == A::NonAtomicType::ZERO || count == A::NonAtomicType::MAX {
+                    None
+                } else {
+                    Some(count - A::NonAtomicType::ONE)
+                }
+            });
+        }
+    }
+
+    /// Returns whether an item is possibly in the filter.
+    pub fn contains<T: Hash + ?Sized>(&self, item: &T, order: Ordering) -> bool {
+        self.counters(item)
+            .all(|counter| counter.load(order) != A::NonAtomicType::ZERO)
+    }
+
+    /// Adds to this filter the items of another filter, adding their counters
+    /// with saturation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the filters have different parameters or seeds.
+    pub fn merge(&self, other: &Self, load_order: Ordering, set_order: Ordering) {
+        assert!(
+            self.counters.len() == other.counters.len()
+                && self.num_hashes == other.num_hashes
+                && self.seed == other.seed,
+            "cannot merge counting Bloom filters with different parameters or seeds"
+        );
+        for (counter, other) in self.counters.iter().zip(other.counters.iter()) {
+            let count = other.load(load_order);
+            if count != A::NonAtomicType::ZERO {
+                let _ = counter.fetch_update(set_order, Ordering::Relaxed, |current| {
+                    Some(current.saturating_add(count))
+                });
+            }
+        }
+    }
+
+    /// Removes all items.
+    pub fn clear(&self, order: Ordering) {
+        for counter in self.counters.iter() {
+            counter.store(A::NonAtomicType::ZERO, order);
+        }
+    }
+
+    /// Serializes the counters of the filter in little-endian order.
+    ///
+    /// The parameters and the seed are not serialized.
+    pub fn to_le_bytes(&self, order: Ordering) -> Vec<u8> {
+        to_le_bytes(&self.counters, order)
+    }
+
+    /// Deserializes a filter serialized by
+    /// [`to_le_bytes`](CountingBloomFilter::to_le_bytes), given its
+    /// parameters and seed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length of `bytes` does not match the
+    /// parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_counters` or `num_hashes` is zero.
+    pub fn from_le_bytes(
+        num_counters: usize,
+        num_hashes: usize,
+        seed: H::Seed,
+        bytes: &[u8],
+    ) -> Result<Self> {
+        let mut result = Self::new(num_counters, num_hashes, seed);
+        result.counters = from_le_bytes(num_counters, bytes)?;
+        Ok(result)
+    }
+}
+
+impl<H: SeedableHasher, A> core::fmt::Debug for CountingBloomFilter<H, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CountingBloomFilter")
+            .field("num_counters", &self.counters.len())
+            .field("num_hashes", &self.num_hashes)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A Count-Min sketch whose counters are [`AtomicUnsignedInt`] values (by
+/// default, [`AtomicU64`]), so that items can be counted concurrently.
+///
+/// The sketch has `depth` rows of `width` counters; an item increments a
+/// counter in each row, selected as in [`BloomFilter`], and the estimate of
+/// its count is the minimum of its counters. Estimates never undercount,
+/// and they overcount by at most ε · *N* with probability 1 − δ, where *N*
+/// is the total count, if `width` is ⌈*e* / ε⌉ and `depth` is ⌈ln(1 / δ)⌉.
+///
+/// Two sketches can be [merged](CountMinSketch::merge) if they have the same
+/// parameters and seed, and the counters of a sketch can be serialized with
+/// [`ToBytes`] using [`to_le_bytes`](CountMinSketch::to_le_bytes).
+pub struct CountMinSketch<H: SeedableHasher, A = AtomicU64> {
+    seed: H::Seed,
+    width: usize,
+    depth: usize,
+    counters: Box<[A]>,
+    _marker: PhantomData<fn() -> H>,
+}
+
+impl<H, A> CountMinSketch<H, A>
+where
+    H: Hasher + SeedableHasher,
+    H::Result: To<u64>,
+    H::Seed: Clone + PartialEq,
+    A: AtomicUnsignedInt,
+    A::NonAtomicType: UnsignedInt + ToBytes + FromBytes,
+{
+    /// Creates a new empty sketch with `depth` rows of `width` counters,
+    /// hashing items with a hasher built from `seed`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `depth` is zero, or if the number of counters
+    /// overflows.
+    pub fn new(width: usize, depth: usize, seed: H::Seed) -> Self {
+        assert!(width > 0, "a Count-Min sketch needs at least one column");
+        assert!(depth > 0, "a Count-Min sketch needs at least one row");
+        let len = width
+            .checked_mul(depth)
+            .expect("the number of counters overflows");
+        Self {
+            seed,
+            width,
+            depth,
+            counters: zeros(len),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of counters per row.
+    #[inline(always)]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows.
+    #[inline(always)]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    #[inline(always)]
+    fn counters<'a, T: Hash + ?Sized>(&'a self, item: &T) -> impl Iterator<Item = &'a A> + 'a {
+        let hash = hash::<H, T>(&self.seed, item);
+        let width = self.width;
+        (0..self.depth).map(move |row| &self.counters[row * width + probe(hash, row, width as u64)])
+    }
+
+    /// Adds `count` to the count of an item.
+    ///
+    /// Counters wrap around on overflow. The ordering has the same meaning
+    /// as in [`AtomicNumber::fetch_add`](crate::AtomicNumber::fetch_add), but
+    /// it applies to each counter separately.
+    pub fn insert<T: Hash + ?Sized>(&self, item: &T, count: A::NonAtomicType, order: Ordering) {
+        for counter in self.counters(item) {
+            counter.fetch_add(count, order);
+        }
+    }
+
+    /// Returns an estimate of the count of an item.
+    pub fn estimate<T: Hash + ?Sized>(&self, item: &T, order: Ordering) -> A::NonAtomicType {
+        self.counters(item)
+            .map(|counter| counter.load(order))
+            .min()
+            .unwrap()
+    }
+
+    /// Adds to this sketch the counts of another sketch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sketches have different parameters or seeds.
+    pub fn merge(&self, other: &Self, load_order: Ordering, add_order: Ordering) {
+        assert!(
+            self.width == other.width && self.depth == other.depth && self.seed == other.seed,
+            "cannot merge Count-Min sketches with different parameters or seeds"
+        );
+        for (counter, other) in self.counters.iter().zip(other.counters.iter()) {
+            let count = other.load(load_order);
+            if count != A::NonAtomicType::ZERO {
+                counter.fetch_add(count, add_order);
+            }
+        }
+    }
+
+    /// Sets all counts to zero.
+    pub fn clear(&self, order: Ordering) {
+        for counter in self.counters.iter() {
+            counter.store(A::NonAtomicType::ZERO, order);
+        }
+    }
+
+    /// Serializes the counters of the sketch, row by row, in little-endian
+    /// order.
+    ///
+    /// The parameters and the seed are not serialized.
+    pub fn to_le_bytes(&self, order: Ordering) -> Vec<u8> {
+        to_le_bytes(&self.counters, order)
+    }
+
+    /// Deserializes a sketch serialized by
+    /// [`to_le_bytes`](CountMinSketch::to_le_bytes), given its parameters
+    /// and seed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length of `bytes` does not match the
+    /// parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `depth` is zero, or if the number of counters
+    /// overflows.
+    pub fn from_le_bytes(width: usize, depth: usize, seed: H::Seed, bytes: &[u8]) -> Result<Self> {
+        let mut result = Self::new(width, depth, seed);
+        result.counters = from_le_bytes(result.counters.len(), bytes)?;
+        Ok(result)
+    }
+}
+
+impl<H: SeedableHasher, A> core::fmt::Debug for CountMinSketch<H, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CountMinSketch")
+            .field("width", &self.width)
+            .field("depth", &self.depth)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A HyperLogLog counter whose registers are [`AtomicUnsignedInt`] values
+/// (by default, [`AtomicU8`]) updated with
+/// [`fetch_max`](crate::AtomicNumber::fetch_max), so that items can be
+/// inserted concurrently.
+///
+/// The counter has 2<sup>`precision`</sup> registers, and its relative
+/// standard error is about 1.04 / 2<sup>`precision` / 2</sup>. Items are
+/// hashed as in [`BloomFilter`].
+///
+/// Two counters can be [merged](HyperLogLog::merge) if they have the same
+/// precision and seed, and the registers of a counter can be serialized with
+/// [`ToBytes`] using [`to_le_bytes`](HyperLogLog::to_le_bytes).
+pub struct HyperLogLog<H: SeedableHasher, A = AtomicU8> {
+    seed: H::Seed,
+    precision: u32,
+    registers: Box<[A]>,
+    _marker: PhantomData<fn() -> H>,
+}
+
+impl<H, A> HyperLogLog<H, A>
+where
+    H: Hasher + SeedableHasher,
+    H::Result: To<u64>,
+    H::Seed: Clone + PartialEq,
+    A: AtomicUnsignedInt,
+    A::NonAtomicType: UnsignedInt + ToBytes + FromBytes + To<u64>,
+    u8: To<A::NonAtomicType>,
+{
+    /// Creates a new empty counter with 2<sup>`precision`</sup> registers,
+    /// hashing items with a hasher built from `seed`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `precision` is not in [4 . . 18].
+    pub fn new(precision: u32, seed: H::Seed) -> Self {
+        assert!(
+            (4..=18).contains(&precision),
+            "the precision of a HyperLogLog counter must be in [4 . . 18], got {precision}"
+        );
+        Self {
+            seed,
+            precision,
+            registers: zeros(1 << precision),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the base-2 logarithm of the number of registers.
+    #[inline(always)]
+    pub fn precision(&self) -> u32 {
+        self.precision
+    }
+
+    /// Inserts an item.
+    ///
+    /// The ordering has the same meaning as in
+    /// [`AtomicNumber::fetch_max`](crate::AtomicNumber::fetch_max); if the
+    /// register is not raised, only its load part applies.
+    pub fn insert<T: Hash + ?Sized>(&self, item: &T, order: Ordering) {
+        let hash = hash::<H, T>(&self.seed, item);
+        let index = (hash >> (64 - self.precision)) as usize;
+        // the sentinel bit bounds the rank by 64 - precision + 1
+        let rest = (hash << self.precision) | (1 << (self.precision - 1));
+        let rank = rest.leading_zeros() as u8 + 1;
+        let register = &self.registers[index];
+        // a load is much cheaper than a read-modify-write, and most
+        // insertions do not change the register
+        if register.load(load_ordering(order)) < rank.to() {
+            register.fetch_max(rank.to(), order);
+        }
+    }
+
+    /// Returns an estimate of the number of distinct inserted items.
+    #[cfg(feature = "std")]
+    pub fn estimate(&self, order: Ordering) -> f64 {
+        let m = self.registers.len() as f64;
+        let mut sum = 0.0;
+        let mut zeros = 0_usize;
+        for register in self.registers.iter() {
+            let rank: u64 = register.load(order).to();
+            zeros += (rank == 0) as usize;
+            // 2⁻ʳᵃⁿᵏ, exactly
+            sum += f64::from_bits((1023 - rank) << 52);
+        }
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        let raw = alpha * m * m / sum;
+        if raw <= 2.5 * m && zeros > 0 {
+            // linear counting is more precise for small cardinalities
+            m * (m / zeros as f64).ln()
+        } else {
+            raw
+        }
+    }
+
+    /// Adds to this counter the items of another counter, computing the
+    /// maximum of their registers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the counters have different precisions or seeds.
+    pub fn merge(&self, other: &Self, load_order: Ordering, max_order: Ordering) {
+        assert!(
+            self.precision == other.precision && self.seed == other.seed,
+            "cannot merge HyperLogLog counters with different precisions or seeds"
+        );
+        for (register, other) in self.registers.iter().zip(other.registers.iter()) {
+            let rank = other.load(load_order);
+            if rank != A::NonAtomicType::ZERO {
+                register.fetch_max(rank, max_order);
+            }
+        }
+    }
+
+    /// Removes all items.
+    pub fn clear(&self, order: Ordering) {
+        for register in self.registers.iter() {
+            register.store(A::NonAtomicType::ZERO, order);
+        }
+    }
+
+    /// Serializes the registers of the counter in little-endian order.
+    ///
+    /// The precision and the seed are not serialized.
+    pub fn to_le_bytes(&self, order: Ordering) -> Vec<u8> {
+        to_le_bytes(&self.registers, order)
+    }
+
+    /// Deserializes a counter serialized by
+    /// [`to_le_bytes`](HyperLogLog::to_le_bytes), given its precision and
+    /// seed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length of `bytes` does not match the
+    /// precision, or if a register is larger than 65 − `precision`, which
+    /// is the largest value an insertion can store.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `precision` is not in [4 . . 18].
+    pub fn from_le_bytes(precision: u32, seed: H::Seed, bytes: &[u8]) -> Result<Self> {
+        let mut result = Self::new(precision, seed);
+        result.registers = from_le_bytes(result.registers.len(), bytes)?;
+        let max_rank = 65 - precision as u64;
+        for (index, register) in result.registers.iter_mut().enumerate() {
+            let rank: u64 = (*register.get_mut()).to();
+            if rank > max_rank {
+                bail!(
+                    "register {} has value {}, but the largest valid value for precision {} is {}",
+                    index,
+                    rank,
+                    precision,
+                    max_rank
+                );
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl<H: SeedableHasher, A> core::fmt::Debug for HyperLogLog<H, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HyperLogLog")
+            .field("precision", &self.precision)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{Rng, RngNext, Sequence, SequenceMut};
+use anyhow::{Result, bail};
+
+/// [`StochasticRoundInto`] : [`StochasticRoundFrom`] = [`Into`] : [`From`].
+/// It's easier to use to specify bounds on generic variables.
+pub trait StochasticRoundInto<W>: Sized {
+    /// Calls `W::stochastic_round_from(self, rng)`.
+    fn stochastic_round<R: Rng + RngNext<u32>>(self, rng: &mut R) -> W;
+}
+
+/// Trait for floats that can be narrowed to a lower precision using
+/// stochastic rounding.
+///
+/// A value that is not exactly representable in `Self` is rounded to one of
+/// its two neighbors, choosing the one farther from zero with probability
+/// proportional to the distance of the value from the one closer to zero.
+/// The result is thus unbiased: its expected value is the original value.
+/// Values that are exactly representable, infinities and NaNs are converted
+/// as by [`DowncastableFrom`](crate::DowncastableFrom). Finite values beyond
+/// the largest finite value of `Self` may round to infinity.
+///
+/// The randomness is drawn from any [`Rng`] that can generate [`u32`] values.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "half")]
+/// # {
+/// use common_traits::{Rng, RngNext, StochasticRoundFrom};
+/// use half::bf16;
+///
+/// pub struct Xorshift32(u32);
+///
+/// impl Rng for Xorshift32 {
+///     type Seed = u32;
+///     fn new(seed: u32) -> Self {
+///         Self(seed.max(1))
+///     }
+/// }
+///
+/// impl RngNext<u32> for Xorshift32 {
+///     fn next_inner(&mut self) -> u32 {
+///         self.0 ^= self.0 << 13;
+///         self.0 ^= self.0 >> 17;
+///         self.0 ^= self.0 << 5;
+///         self.0
+///     }
+/// }
+///
+/// let mut rng = Xorshift32::new(42);
+/// let x = 1.0_f32 + f32::EPSILON;
+/// let y = bf16::stochastic_round_from(x, &mut rng);
+/// assert!(y == bf16::ONE || y == bf16::ONE + bf16::EPSILON);
+/// # }
+/// ```
+pub trait StochasticRoundFrom<W>: Sized {
+    /// Narrows `value` to `Self` using stochastic rounding.
+    fn stochastic_round_from<R: Rng + RngNext<u32>>(value: W, rng: &mut R) -> Self;
+
+    /// Narrows all the elements of `src` into `dst` using stochastic rounding.
+    ///
+    /// Returns an error if the two sequences have different lengths.
+    fn stochastic_round_sequence<S, D, R>(src: &S, dst: &mut D, rng: &mut R) -> Result<()>
+    where
+        S: Sequence<Item = W> + ?Sized,
+        D: SequenceMut<Item = Self> + ?Sized,
+        R: Rng + RngNext<u32>,
+    {
+        if src.len() != dst.len() {
+            bail!(
+                "The source Sequence has length {} but the destination Sequence has length {}",
+                src.len(),
+                dst.len(),
+            );
+        }
+        for (index, value) in src.iter().enumerate() {
+            unsafe { dst.set_unchecked(index, Self::stochastic_round_from(value, rng)) };
+        }
+        Ok(())
+    }
+}
+
+/// [`StochasticRoundFrom`] implies [`StochasticRoundInto`].
+impl<T, U> StochasticRoundInto<U> for T
+where
+    U: StochasticRoundFrom<T>,
+{
+    #[inline(always)]
+    fn stochastic_round<R: Rng + RngNext<u32>>(self, rng: &mut R) -> U {
+        U::stochastic_round_from(self, rng)
+    }
+}
+
+/// Returns `true` with probability `p`, for `p` in [0 . . 1).
+#[inline(always)]
+fn bernoulli<R: Rng + RngNext<u32>>(p: f64, rng: &mut R) -> bool {
+    (rng.next::<u32>() as f64) < p * (1_u64 << 32) as f64
+}
+
+macro_rules! impl_stochastic_round {
+    ($($src:ty => $dst:ty,)*) => {$(
+impl StochasticRoundFrom<$src> for $dst {
+    #[inline]
+    fn stochastic_round_from<R: Rng + RngNext<u32>>(value: $src, rng: &mut R) -> Self {
+        let x = value as f64;
+        let nearest = <$dst>::from_f64(x);
+        if !x.is_finite() || nearest.to_f64() == x {
+            return nearest;
+        }
+        // Work on the magnitude: the bit patterns of non-negative floats are
+        // ordered as their values, so neighbors differ by one.
+        let sign = nearest.to_bits() & 0x8000;
+        let magnitude = nearest.to_bits() & 0x7FFF;
+        let (lo, hi) = if nearest.to_f64().abs() < x.abs() {
+            (magnitude, magnitude + 1)
+        } else {
+            (magnitude - 1, magnitude)
+        };
+        let lo_value = <$dst>::from_bits(lo).to_f64();
+        let hi_value = <$dst>::from_bits(hi);
+        // If the upper neighbor is infinite, we use the gap of the last binade
+        let ulp = if hi_value.is_infinite() {
+            lo_value - <$dst>::from_bits(lo - 1).to_f64()
+        } else {
+            hi_value.to_f64() - lo_value
+        };
+        let magnitude = if bernoulli((x.abs() - lo_value) / ulp, rng) {
+            hi
+        } else {
+            lo
+        };
+        <$dst>::from_bits(sign | magnitude)
+    }
+}
+    )*};
+}
+
+impl_stochastic_round!(
+    f32 => half::f16,
+    f64 => half::f16,
+    f64 => half::bf16,
+);
+
+impl StochasticRoundFrom<f32> for half::bf16 {
+    #[inline]
+    fn stochastic_round_from<R: Rng + RngNext<u32>>(value: f32, rng: &mut R) -> Self {
+        if value.is_nan() {
+            return half::bf16::from_f32(value);
+        }
+        // bf16 is a truncated f32, so adding a random value to the
+        // discarded bits and truncating rounds up with the right probability
+        let bits = value.to_bits().wrapping_add(rng.next::<u32>() & 0xFFFF);
+        half::bf16::from_bits((bits >> 16) as u16)
+    }
+}
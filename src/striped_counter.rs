@@ -0,0 +1,342 @@
+use crate::{AtomicNumber, Number};
+use core::sync::atomic::Ordering;
+
+/// A counter that can be updated concurrently, such as an [`AtomicNumber`] or
+/// a [`StripedCounter`].
+///
+/// The methods of this trait have a `counter_` prefix so that they do not
+/// clash with the methods of the same name of [`Atomic`](crate::Atomic) and
+/// [`AtomicNumber`], which they forward to.
+///
+/// # Examples
+///
+/// ```rust
+/// use common_traits::{AtomicCounter, StripedCounter};
+/// use core::sync::atomic::{AtomicUsize, Ordering};
+///
+/// fn count_even(values: &[usize], counter: &impl AtomicCounter<Value = usize>) {
+///     for &value in values {
+///         if value % 2 == 0 {
+///             counter.counter_fetch_add(1, Ordering::Relaxed);
+///         }
+///     }
+/// }
+///
+/// let plain = AtomicUsize::new(0);
+/// let striped = StripedCounter::<AtomicUsize>::default();
+/// count_even(&[1, 2, 4], &plain);
+/// count_even(&[1, 2, 4], &striped);
+/// assert_eq!(plain.counter_load(Ordering::Relaxed), 2);
+/// assert_eq!(striped.counter_load(Ordering::Relaxed), 2);
+/// ```
+pub trait AtomicCounter {
+    /// The type of the value of the counter.
+    type Value;
+
+    /// Adds to the counter, returning the previous value of the updated
+    /// part of the counter (see [`StripedCounter::fetch_add`]).
+    fn counter_fetch_add(&self, value: Self::Value, order: Ordering) -> Self::Value;
+
+    /// Subtracts from the counter, returning the previous value of the
+    /// updated part of the counter (see [`StripedCounter::fetch_sub`]).
+    fn counter_fetch_sub(&self, value: Self::Value, order: Ordering) -> Self::Value;
+
+    /// Returns the value of the counter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is [`Release`](`core::sync::atomic::Ordering::Release`)
+    /// or [`AcqRel`](`core::sync::atomic::Ordering::AcqRel`).
+    fn counter_load(&self, order: Ordering) -> Self::Value;
+
+    /// Sets the value of the counter, returning the previous value.
+    fn counter_swap(&self, value: Self::Value, order: Ordering) -> Self::Value;
+}
+
+impl<A: AtomicNumber> AtomicCounter for A
+where
+    A::NonAtomicType: Number,
+{
+    type Value = A::NonAtomicType;
+
+    #[inline(always)]
+    fn counter_fetch_add(&self, value: Self::Value, order: Ordering) -> Self::Value {
+        self.fetch_add(value, order)
+    }
+
+    #[inline(always)]
+    fn counter_fetch_sub(&self, value: Self::Value, order: Ordering) -> Self::Value {
+        self.fetch_sub(value, order)
+    }
+
+    #[inline(always)]
+    fn counter_load(&self, order: Ordering) -> Self::Value {
+        self.load(order)
+    }
+
+    #[inline(always)]
+    fn counter_swap(&self, value: Self::Value, order: Ordering) -> Self::Value {
+        self.swap(value, order)
+    }
+}
+
+/// A shard of a [`StripedCounter`], padded to avoid false sharing.
+#[repr(align(64))]
+struct Shard<A>(A);
+
+/// A counter spreading updates across `N` cache-padded shards of an
+/// [`AtomicNumber`], such as [`AtomicUsize`](core::sync::atomic::AtomicUsize)
+/// or [`AtomicF64`](crate::AtomicF64).
+///
+/// Many threads adding to the same atomic number contend for the same cache
+/// line, and the cost of the contention grows quickly with the number of
+/// threads (in particular for floating-point numbers, whose additions are
+/// compare-and-swap loops). A striped counter makes each thread add to its own
+/// shard, and [`load`](StripedCounter::load) sums the shards, so it should be
+/// used when updates are much more frequent than reads.
+///
+/// The counter cannot implement [`AtomicNumber`], as the latter associates a
+/// single atomic type with each number type; the methods of the counter have,
+/// however, the same names and arguments as those of [`AtomicNumber`], and
+/// both the counter and atomic numbers implement [`AtomicCounter`], so
+/// generic code can accept either.
+///
+/// The shard used by [`fetch_add`](StripedCounter::fetch_add) and
+/// [`fetch_sub`](StripedCounter::fetch_sub) depends on the calling thread:
+/// with the `std` feature threads are assigned shards in a round-robin
+/// fashion, whereas without it the shard is derived from the address of the
+/// stack of the calling thread. The methods with a `_with_hint` suffix use
+/// instead the shard of index `hint % N`, which is useful when the caller
+/// already has a small worker index.
+///
+/// Methods involving all shards are not atomic as a whole: for example, the
+/// result of [`load`](StripedCounter::load) might include only some of the
+/// updates that are concurrent with it. Moreover, the order in which shards
+/// are summed depends on how updates are distributed, so the result of
+/// floating-point sums might differ slightly from a sequential sum.
+///
+/// # Examples
+///
+/// ```rust
+/// use common_traits::{AtomicF64, StripedCounter};
+/// use core::sync::atomic::Ordering;
+///
+/// let rank = StripedCounter::<AtomicF64>::default();
+/// std::thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| {
+///             for _ in 0..100 {
+///                 rank.fetch_add(0.5, Ordering::Relaxed);
+///             }
+///         });
+///     }
+/// });
+/// assert_eq!(rank.reset(Ordering::Relaxed), 200.0);
+/// assert_eq!(rank.load(Ordering::Relaxed), 0.0);
+/// ```
+pub struct StripedCounter<A, const N: usize = 16> {
+    shards: [Shard<A>; N],
+}
+
+#[cfg(feature = "std")]
+fn thread_hint() -> usize {
+    use core::sync::atomic::AtomicUsize;
+    static NEXT_THREAD: AtomicUsize = AtomicUsize::new(0);
+    std::thread_local! {
+        static THREAD: usize = NEXT_THREAD.fetch_add(1, Ordering::Relaxed);
+    }
+    THREAD.with(|thread| *thread)
+}
+
+#[cfg(not(feature = "std"))]
+fn thread_hint() -> usize {
+    // Stacks of different threads are far apart, so we discard the low bits
+    // of the address of a local variable and hash the rest
+    let local = 0_u8;
+    let address = &local as *const u8 as usize;
+    (address >> 12).wrapping_mul(0x9E37_79B9) >> 8
+}
+
+impl<A: AtomicNumber, const N: usize> StripedCounter<A, N>
+where
+    A::NonAtomicType: Number,
+{
+    const NONEMPTY: () = assert!(N > 0, "a striped counter needs at least one shard");
+
+    /// Creates a new counter with the given initial value.
+    ///
+    /// Creating a counter with no shards is a compile-time error.
+    #[inline(always)]
+    pub fn new(value: A::NonAtomicType) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::NONEMPTY;
+        let shards = core::array::from_fn(|i| {
+            Shard(A::new(if i == 0 {
+                value
+            } else {
+                A::NonAtomicType::ZERO
+            }))
+        });
+        Self { shards }
+    }
+
+    /// Returns the number of shards.
+    #[inline(always)]
+    pub const fn num_shards(&self) -> usize {
+        N
+    }
+
+    #[inline(always)]
+    fn shard(&self, hint: usize) -> &A {
+        &self.shards[hint % N].0
+    }
+
+    /// Adds to the shard of the calling thread, returning the previous value
+    /// of the shard (not of the counter).
+    ///
+    /// The ordering has the same meaning as in [`AtomicNumber::fetch_add`],
+    /// but it applies only to the shard.
+    #[inline(always)]
+    pub fn fetch_add(&self, value: A::NonAtomicType, order: Ordering) -> A::NonAtomicType {
+        self.fetch_add_with_hint(value, thread_hint(), order)
+    }
+
+    /// Subtracts from the shard of the calling thread, returning the previous
+    /// value of the shard (not of the counter).
+    ///
+    /// The ordering has the same meaning as in [`AtomicNumber::fetch_sub`],
+    /// but it applies only to the shard.
+    #[inline(always)]
+    pub fn fetch_sub(&self, value: A::NonAtomicType, order: Ordering) -> A::NonAtomicType {
+        self.fetch_sub_with_hint(value, thread_hint(), order)
+    }
+
+    /// Adds to the shard of index `hint % N`, returning the previous value of
+    /// the shard (not of the counter).
+    #[inline(always)]
+    pub fn fetch_add_with_hint(
+        &self,
+        value: A::NonAtomicType,
+        hint: usize,
+        order: Ordering,
+    ) -> A::NonAtomicType {
+        self.shard(hint).fetch_add(value, order)
+    }
+
+    /// Subtracts from the shard of index `hint % N`, returning the previous
+    /// value of the shard (not of the counter).
+    #[inline(always)]
+    pub fn fetch_sub_with_hint(
+        &self,
+        value: A::NonAtomicType,
+        hint: usize,
+        order: Ordering,
+    ) -> A::NonAtomicType {
+        self.shard(hint).fetch_sub(value, order)
+    }
+
+    /// Sums the values of the shards.
+    ///
+    /// Each shard is loaded with ordering `order`, which has the same meaning
+    /// as in [`Atomic::load`](crate::Atomic::load).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` is [`Release`](`core::sync::atomic::Ordering::Release`)
+    /// or [`AcqRel`](`core::sync::atomic::Ordering::AcqRel`).
+    pub fn load(&self, order: Ordering) -> A::NonAtomicType {
+        Self::sum(self.shards.iter().map(|shard| shard.0.load(order)))
+    }
+
+    /// Stores `value` in the first shard and zero in the others, returning
+    /// the sum of the previous values of the shards.
+    ///
+    /// Each shard is swapped with ordering `order`, which has the same meaning
+    /// as in [`Atomic::swap`](crate::Atomic::swap). Updates concurrent with this method are not
+    /// lost: they are either included in the returned value or applied to the
+    /// new value.
+    pub fn swap(&self, value: A::NonAtomicType, order: Ordering) -> A::NonAtomicType {
+        Self::sum(self.shards.iter().enumerate().map(|(i, shard)| {
+            shard.0.swap(
+                if i == 0 {
+                    value
+                } else {
+                    A::NonAtomicType::ZERO
+                },
+                order,
+            )
+        }))
+    }
+
+    /// Zeroes the counter, returning the sum of the previous values of the
+    /// shards.
+    ///
+    /// This method is equivalent to [`swap`](StripedCounter::swap) with
+    /// argument zero.
+    #[inline(always)]
+    pub fn reset(&self, order: Ordering) -> A::NonAtomicType {
+        self.swap(A::NonAtomicType::ZERO, order)
+    }
+
+    /// Consumes the counter and returns the sum of the values of the shards.
+    pub fn into_inner(self) -> A::NonAtomicType {
+        Self::sum(self.shards.into_iter().map(|shard| shard.0.into_inner()))
+    }
+
+    /// Sums values with the same semantics of [`AtomicNumber::fetch_add`]
+    /// (e.g., wrapping around on overflow for integers), so that the result
+    /// does not depend on the way updates were distributed among shards.
+    fn sum(values: impl Iterator<Item = A::NonAtomicType>) -> A::NonAtomicType {
+        let sum = A::new(A::NonAtomicType::ZERO);
+        for value in values {
+            sum.fetch_add(value, Ordering::Relaxed);
+        }
+        sum.into_inner()
+    }
+}
+
+impl<A: AtomicNumber, const N: usize> AtomicCounter for StripedCounter<A, N>
+where
+    A::NonAtomicType: Number,
+{
+    type Value = A::NonAtomicType;
+
+    #[inline(always)]
+    fn counter_fetch_add(&self, value: Self::Value, order: Ordering) -> Self::Value {
+        self.fetch_add(value, order)
+    }
+
+    #[inline(always)]
+    fn counter_fetch_sub(&self, value: Self::Value, order: Ordering) -> Self::Value {
+        self.fetch_sub(value, order)
+    }
+
+    #[inline(always)]
+    fn counter_load(&self, order: Ordering) -> Self::Value {
+        self.load(order)
+    }
+
+    #[inline(always)]
+    fn counter_swap(&self, value: Self::Value, order: Ordering) -> Self::Value {
+        self.swap(value, order)
+    }
+}
+
+impl<A: AtomicNumber, const N: usize> Default for StripedCounter<A, N>
+where
+    A::NonAtomicType: Number,
+{
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new(A::NonAtomicType::ZERO)
+    }
+}
+
+impl<A: AtomicNumber, const N: usize> core::fmt::Debug for StripedCounter<A, N>
+where
+    A::NonAtomicType: Number + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
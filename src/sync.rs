@@ -0,0 +1,73 @@
+//! The atomic types on which the atomic implementations of the crate are
+//! based.
+//!
+//! By default, this module re-exports the types of [`core::sync::atomic`].
+//! The backend can be changed as follows:
+//!
+//! - with the `portable-atomic` feature, the atomic types that are not
+//!   available natively on the target (e.g., [`AtomicU64`] on 32-bit
+//!   microcontrollers) are taken from the
+//!   [`portable-atomic`](https://crates.io/crates/portable-atomic) crate, so
+//!   that, for example, [`AtomicF64`](crate::AtomicF64) is available
+//!   everywhere; native types are always taken from [`core::sync::atomic`],
+//!   so enabling the feature does not change the atomic types of targets
+//!   that already support them;
+//!
+//! - compiling with `RUSTFLAGS="--cfg loom"`, all types are taken from the
+//!   [`loom`](https://crates.io/crates/loom) crate, so that lock-free
+//!   algorithms written against [`Atomic`](crate::Atomic) and
+//!   [`AtomicNumber`](crate::AtomicNumber) can be model-checked; the crate
+//!   is re-exported as `sync::loom` so that tests can use the same version.
+//!
+//! Loom atomics do not have the memory layout of the underlying value: under
+//! `cfg(loom)`, the methods of [`Atomic`](crate::Atomic) and
+//! [`IntoAtomic`](crate::IntoAtomic) converting between references to atomic
+//! and non-atomic values (e.g., [`get_mut`](crate::Atomic::get_mut) or
+//! [`get_mut_slice`](crate::Atomic::get_mut_slice)) panic. Moreover, the
+//! internal locks of [`AtomicU128`](crate::AtomicU128),
+//! [`SeqLockAtomic`](crate::SeqLockAtomic) and
+//! [`StripedCounter`](crate::StripedCounter) are not modelled.
+
+#[cfg(not(loom))]
+pub use core::sync::atomic::AtomicBool;
+#[cfg(all(not(loom), target_has_atomic = "8"))]
+pub use core::sync::atomic::{AtomicI8, AtomicU8};
+#[cfg(all(not(loom), target_has_atomic = "16"))]
+pub use core::sync::atomic::{AtomicI16, AtomicU16};
+#[cfg(all(not(loom), target_has_atomic = "32"))]
+pub use core::sync::atomic::{AtomicI32, AtomicU32};
+#[cfg(all(not(loom), target_has_atomic = "64"))]
+pub use core::sync::atomic::{AtomicI64, AtomicU64};
+#[cfg(all(not(loom), target_has_atomic = "ptr"))]
+pub use core::sync::atomic::{AtomicIsize, AtomicUsize};
+
+#[cfg(all(not(loom), feature = "portable-atomic", not(target_has_atomic = "8")))]
+pub use portable_atomic::{AtomicI8, AtomicU8};
+#[cfg(all(not(loom), feature = "portable-atomic", not(target_has_atomic = "16")))]
+pub use portable_atomic::{AtomicI16, AtomicU16};
+#[cfg(all(not(loom), feature = "portable-atomic", not(target_has_atomic = "32")))]
+pub use portable_atomic::{AtomicI32, AtomicU32};
+#[cfg(all(not(loom), feature = "portable-atomic", not(target_has_atomic = "64")))]
+pub use portable_atomic::{AtomicI64, AtomicU64};
+#[cfg(all(not(loom), feature = "portable-atomic", not(target_has_atomic = "ptr")))]
+pub use portable_atomic::{AtomicIsize, AtomicUsize};
+
+#[cfg(loom)]
+pub use loom::sync::atomic::{
+    AtomicBool, AtomicI8, AtomicI16, AtomicI32, AtomicI64, AtomicIsize, AtomicU8, AtomicU16,
+    AtomicU32, AtomicU64, AtomicUsize,
+};
+
+#[cfg(loom)]
+pub use loom;
+
+/// Panics, as the atomic types of the loom backend do not have the memory
+/// layout of the underlying values.
+///
+/// Must be called under `cfg(loom)` before reinterpreting references to
+/// atomic types as references to non-atomic types, or vice versa.
+#[cfg(loom)]
+#[inline(always)]
+pub(crate) fn check_layout() {
+    panic!("atomic types of the loom backend cannot be converted to or from non-atomic types");
+}
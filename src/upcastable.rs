@@ -50,6 +50,66 @@ impl UpcastableFrom<$base_type> for $ty {
 impl_upcasts!(u8, u16, u32, u64, u128,);
 impl_upcasts!(i8, i16, i32, i64, i128,);
 
+macro_rules! impl_cross_upcasts {
+    ($($base_type:ty => $($ty:ty),*;)*) => {$($(
+impl UpcastableFrom<$base_type> for $ty {
+    #[inline(always)]
+    fn upcast_from(value: $base_type) -> Self {
+        value as $ty
+    }
+}
+    )*)*};
+}
+
+// Unsigned to strictly wider signed types, and integers to floats whose
+// mantissa can represent all their values exactly.
+impl_cross_upcasts!(
+    u8 => i16, i32, i64, i128, f32, f64;
+    u16 => i32, i64, i128, f32, f64;
+    u32 => i64, i128, f64;
+    u64 => i128;
+    i8 => f32, f64;
+    i16 => f32, f64;
+    i32 => f64;
+);
+
+#[cfg(any(
+    target_pointer_width = "16",
+    target_pointer_width = "32",
+    target_pointer_width = "64",
+))]
+impl_cross_upcasts!(
+    u8 => isize;
+    usize => i128;
+);
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64",))]
+impl_cross_upcasts!(
+    u16 => isize;
+);
+
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32",))]
+impl_cross_upcasts!(
+    usize => i64;
+);
+
+#[cfg(target_pointer_width = "64")]
+impl_cross_upcasts!(
+    u32 => isize;
+);
+
+#[cfg(target_pointer_width = "16")]
+impl_cross_upcasts!(
+    usize => i32, f32, f64;
+    isize => f32, f64;
+);
+
+#[cfg(target_pointer_width = "32")]
+impl_cross_upcasts!(
+    usize => f64;
+    isize => f64;
+);
+
 #[cfg(any(
     target_pointer_width = "16",
     target_pointer_width = "32",
@@ -244,4 +304,28 @@ mod half_impl {
             value.to_f64()
         }
     }
+    impl UpcastableFrom<u8> for half::f16 {
+        #[inline(always)]
+        fn upcast_from(value: u8) -> Self {
+            half::f16::from_f32(value as f32)
+        }
+    }
+    impl UpcastableFrom<i8> for half::f16 {
+        #[inline(always)]
+        fn upcast_from(value: i8) -> Self {
+            half::f16::from_f32(value as f32)
+        }
+    }
+    impl UpcastableFrom<u8> for half::bf16 {
+        #[inline(always)]
+        fn upcast_from(value: u8) -> Self {
+            half::bf16::from_f32(value as f32)
+        }
+    }
+    impl UpcastableFrom<i8> for half::bf16 {
+        #[inline(always)]
+        fn upcast_from(value: i8) -> Self {
+            half::bf16::from_f32(value as f32)
+        }
+    }
 }
@@ -0,0 +1,85 @@
+use common_traits::*;
+use core::sync::atomic::{AtomicU8, AtomicU64, AtomicUsize, Ordering};
+
+#[test]
+fn test_atomic_bit_set() {
+    let bits = [const { AtomicU8::new(0) }; 3];
+    assert_eq!(bits.num_bits(), 24);
+    assert!(!bits.set_bit(0, Ordering::Relaxed));
+    assert!(bits.set_bit(0, Ordering::Relaxed));
+    assert!(!bits.toggle_bit(9, Ordering::Relaxed));
+    assert!(bits.get_bit(9, Ordering::Relaxed));
+    assert!(!bits.test_and_set(23, Ordering::AcqRel));
+    assert!(bits.test_and_set(23, Ordering::Release));
+    assert_eq!(bits.count_ones(Ordering::Relaxed), 3);
+    assert_eq!(
+        bits.iter_ones(Ordering::Relaxed).collect::<Vec<_>>(),
+        vec![0, 9, 23]
+    );
+    assert_eq!(bits.select(0, Ordering::Relaxed), Some(0));
+    assert_eq!(bits.select(1, Ordering::Relaxed), Some(9));
+    assert_eq!(bits.select(2, Ordering::Relaxed), Some(23));
+    assert_eq!(bits.select(3, Ordering::Relaxed), None);
+
+    assert!(bits.toggle_bit(9, Ordering::Relaxed));
+    assert!(!bits.get_bit(9, Ordering::Relaxed));
+    assert!(bits.clear_bit(0, Ordering::Relaxed));
+    assert!(!bits.clear_bit(0, Ordering::Relaxed));
+
+    let mut words = [0xFF, 0, 0];
+    bits.swap_words(&mut words, Ordering::Relaxed);
+    assert_eq!(words, [0, 0, 0x80]);
+    assert_eq!(
+        bits.iter_ones(Ordering::Relaxed).collect::<Vec<_>>(),
+        (0..8).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_atomic_bit_set_out_of_bounds() {
+    let bits = [const { AtomicU64::new(0) }; 2];
+    bits.set_bit(128, Ordering::Relaxed);
+}
+
+#[test]
+fn test_atomic_bit_set_chunks() {
+    let bits: Vec<AtomicU64> = (0..8).map(|_| AtomicU64::new(0)).collect();
+    for i in (0..bits.num_bits()).step_by(7) {
+        bits.set_bit(i, Ordering::Relaxed);
+    }
+    let ones = bits
+        .chunks(3)
+        .enumerate()
+        .flat_map(|(i, chunk)| {
+            chunk
+                .iter_ones(Ordering::Relaxed)
+                .map(move |bit| i * 3 * 64 + bit)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(ones, (0..512).step_by(7).collect::<Vec<_>>());
+}
+
+#[cfg(not(miri))]
+#[test]
+fn test_atomic_bit_set_concurrent() {
+    let visited: Vec<AtomicUsize> = (0..16).map(|_| AtomicUsize::new(0)).collect();
+    let num_bits = visited.num_bits();
+    let first_visits = AtomicUsize::new(0);
+    std::thread::scope(|s| {
+        for t in 0..4 {
+            let visited = &visited;
+            let first_visits = &first_visits;
+            s.spawn(move || {
+                for i in 0..num_bits {
+                    let node = (i * 5 + t) % num_bits;
+                    if !visited.test_and_set(node, Ordering::Relaxed) {
+                        first_visits.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+            });
+        }
+    });
+    assert_eq!(first_visits.into_inner(), num_bits);
+    assert_eq!(visited.count_ones(Ordering::Relaxed), num_bits);
+}
@@ -0,0 +1,68 @@
+use common_traits::sync::{AtomicU8, AtomicU64};
+use common_traits::*;
+use core::sync::atomic::Ordering;
+
+const SET: Ordering = Ordering::AcqRel;
+const FETCH: Ordering = Ordering::Acquire;
+
+#[test]
+fn test_atomic_bitfield() {
+    let word = AtomicU64::new(u64::MAX);
+    word.store_bitfield(4, 8, 0b1010, SET, FETCH);
+    assert_eq!(word.load(Ordering::Relaxed), !0xF0 | 0xA0);
+    assert_eq!(word.load_bitfield(4, 8, Ordering::Relaxed), 0b1010);
+    assert_eq!(word.load_bitfield(0, 4, Ordering::Relaxed), 0xF);
+
+    assert_eq!(word.fetch_add_bitfield(4, 8, 5, SET, FETCH), Ok(0b1010));
+    assert_eq!(word.fetch_add_bitfield(4, 8, 1, SET, FETCH), Err(0xF));
+    assert_eq!(word.fetch_sub_bitfield(4, 8, 15, SET, FETCH), Ok(0xF));
+    assert_eq!(word.fetch_sub_bitfield(4, 8, 1, SET, FETCH), Err(0));
+    assert_eq!(word.load(Ordering::Relaxed), !0xF0);
+
+    assert_eq!(
+        word.compare_exchange_bitfield(60, 64, 0, 3, SET, FETCH),
+        Err(0xF)
+    );
+    assert_eq!(
+        word.compare_exchange_bitfield(60, 64, 0xF, 3, SET, FETCH),
+        Ok(0xF)
+    );
+    assert_eq!(word.load(Ordering::Relaxed), (!0xF0 & !(0xC << 60)));
+
+    // Whole word
+    word.store_bitfield(0, 64, 7, SET, FETCH);
+    assert_eq!(word.load_bitfield(0, 64, Ordering::Relaxed), 7);
+    assert_eq!(word.fetch_add_bitfield(0, 64, u64::MAX, SET, FETCH), Err(7));
+    assert_eq!(word.fetch_add_bitfield(0, 64, 1, SET, FETCH), Ok(7));
+
+    let byte = AtomicU8::new(0);
+    assert_eq!(byte.fetch_add_bitfield(7, 8, 1, SET, FETCH), Ok(0));
+    assert_eq!(byte.fetch_add_bitfield(7, 8, 1, SET, FETCH), Err(1));
+    assert_eq!(byte.load(Ordering::Relaxed), 0x80);
+}
+
+#[cfg(not(miri))]
+#[test]
+fn test_atomic_bitfield_concurrent() {
+    // Sixteen 4-bit counters in a word, as in a counting Bloom filter
+    let counters = AtomicU64::new(0);
+    std::thread::scope(|s| {
+        for t in 0..4 {
+            let counters = &counters;
+            s.spawn(move || {
+                for i in 0..16 {
+                    // Counter i is incremented 4 + i times, saturating at 15
+                    for _ in 0..(4 + i) / 4 + usize::from(t < (4 + i) % 4) {
+                        let _ = counters.fetch_add_bitfield(4 * i, 4 * i + 4, 1, SET, FETCH);
+                    }
+                }
+            });
+        }
+    });
+    for i in 0..16 {
+        assert_eq!(
+            counters.load_bitfield(4 * i, 4 * i + 4, Ordering::Relaxed),
+            Ord::min(4 + i as u64, 15)
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use common_traits::*;
+use core::sync::atomic::Ordering;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(u8)]
+enum State {
+    #[default]
+    Empty = 0,
+    Busy = 1,
+    Full = 2,
+}
+
+impl AsBytes for State {
+    const BYTES: usize = 1;
+    const BITS: usize = 8;
+    type Bytes = [u8; 1];
+}
+
+impl ToBytes for State {
+    fn to_be_bytes(self) -> [u8; 1] {
+        [self as u8]
+    }
+    fn to_le_bytes(self) -> [u8; 1] {
+        [self as u8]
+    }
+    fn to_ne_bytes(self) -> [u8; 1] {
+        [self as u8]
+    }
+}
+
+impl FromBytes for State {
+    fn from_be_bytes(bytes: [u8; 1]) -> Self {
+        Self::from_ne_bytes(bytes)
+    }
+    fn from_le_bytes(bytes: [u8; 1]) -> Self {
+        Self::from_ne_bytes(bytes)
+    }
+    fn from_ne_bytes([byte]: [u8; 1]) -> Self {
+        match byte {
+            0 => State::Empty,
+            1 => State::Busy,
+            2 => State::Full,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl_into_atomic_cell!(State);
+unsafe impl SameAs<AtomicCell<State>> for State {}
+
+/// A version number and an index packed in 16 bytes.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C, align(16))]
+struct Versioned {
+    version: u64,
+    index: u64,
+}
+
+impl AsBytes for Versioned {
+    const BYTES: usize = 16;
+    const BITS: usize = 128;
+    type Bytes = [u8; 16];
+}
+
+impl ToBytes for Versioned {
+    fn to_be_bytes(self) -> [u8; 16] {
+        (((self.version as u128) << 64) | self.index as u128).to_be_bytes()
+    }
+    fn to_le_bytes(self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        bytes[..8].copy_from_slice(&self.version.to_le_bytes());
+        bytes[8..].copy_from_slice(&self.index.to_le_bytes());
+        bytes
+    }
+    fn to_ne_bytes(self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        bytes[..8].copy_from_slice(&self.version.to_ne_bytes());
+        bytes[8..].copy_from_slice(&self.index.to_ne_bytes());
+        bytes
+    }
+}
+
+impl FromBytes for Versioned {
+    fn from_be_bytes(bytes: [u8; 16]) -> Self {
+        let x = u128::from_be_bytes(bytes);
+        Versioned {
+            version: (x >> 64) as u64,
+            index: x as u64,
+        }
+    }
+    fn from_le_bytes(bytes: [u8; 16]) -> Self {
+        Versioned {
+            version: u64::from_le_bytes(bytes[..8].try_into().unwrap()),
+            index: u64::from_le_bytes(bytes[8..].try_into().unwrap()),
+        }
+    }
+    fn from_ne_bytes(bytes: [u8; 16]) -> Self {
+        Versioned {
+            version: u64::from_ne_bytes(bytes[..8].try_into().unwrap()),
+            index: u64::from_ne_bytes(bytes[8..].try_into().unwrap()),
+        }
+    }
+}
+
+impl_into_atomic_cell!(Versioned);
+unsafe impl SameAs<AtomicCell<Versioned>> for Versioned {}
+
+#[test]
+fn test_atomic_cell_enum() {
+    let state = AtomicCell::<State>::default();
+    assert_eq!(state.load(Ordering::Relaxed), State::Empty);
+    assert_eq!(
+        state.compare_exchange(
+            State::Empty,
+            State::Busy,
+            Ordering::Acquire,
+            Ordering::Relaxed
+        ),
+        Ok(State::Empty)
+    );
+    assert_eq!(
+        state.compare_exchange_weak(
+            State::Empty,
+            State::Full,
+            Ordering::Acquire,
+            Ordering::Relaxed
+        ),
+        Err(State::Busy)
+    );
+    state.store(State::Full, Ordering::Release);
+    assert_eq!(state.swap(State::Empty, Ordering::Relaxed), State::Full);
+    assert_eq!(format!("{:?}", state), "Empty");
+}
+
+#[test]
+fn test_atomic_cell_slices() {
+    let mut states = [State::Empty, State::Busy, State::Full];
+    let atomic = State::from_mut_slice(&mut states);
+    atomic[0].store(State::Full, Ordering::Relaxed);
+    *atomic[1].get_mut() = State::Empty;
+    assert_eq!(
+        State::get_mut_slice(atomic),
+        [State::Full, State::Empty, State::Full]
+    );
+
+    let atomic = State::into_atomic_array([State::Busy, State::Empty]);
+    assert_eq!(
+        AtomicCell::into_non_atomic_array(atomic),
+        [State::Busy, State::Empty]
+    );
+}
+
+#[test]
+fn test_atomic_cell_wide() {
+    let x = Versioned {
+        version: 1,
+        index: 42,
+    }
+    .to_atomic();
+    assert_eq!(
+        x.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |v| Some(Versioned {
+            version: v.version + 1,
+            index: 7,
+        })),
+        Ok(Versioned {
+            version: 1,
+            index: 42
+        })
+    );
+    let mut x = x;
+    assert_eq!(
+        *x.get_mut(),
+        Versioned {
+            version: 2,
+            index: 7
+        }
+    );
+    assert_eq!(
+        x.into_inner(),
+        Versioned {
+            version: 2,
+            index: 7
+        }
+    );
+}
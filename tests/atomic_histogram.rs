@@ -0,0 +1,203 @@
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use common_traits::sync::{AtomicU8, AtomicU32, AtomicU64};
+use common_traits::*;
+use core::sync::atomic::Ordering;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+const LAYOUTS: [BucketLayout; 7] = [
+    BucketLayout::Linear {
+        min: 0,
+        width: 1,
+        len: 100,
+    },
+    BucketLayout::Linear {
+        min: 1000,
+        width: 7,
+        len: 50,
+    },
+    BucketLayout::Linear {
+        min: 0,
+        width: u64::MAX / 3,
+        len: 4,
+    },
+    BucketLayout::Log2,
+    BucketLayout::Hdr { precision: 0 },
+    BucketLayout::Hdr { precision: 3 },
+    BucketLayout::Hdr { precision: 10 },
+];
+
+#[test]
+fn test_layouts() {
+    let mut rng = SmallRng::seed_from_u64(0);
+    for layout in LAYOUTS {
+        // the buckets partition the u64 values in increasing order
+        let mut next = 0;
+        for index in 0..layout.num_buckets() {
+            let range = layout.range(index);
+            assert_eq!(*range.start(), next, "{layout:?} {index}");
+            assert!(range.start() <= range.end());
+            assert_eq!(layout.index(*range.start()), index, "{layout:?}");
+            assert_eq!(layout.index(*range.end()), index, "{layout:?}");
+            next = range.end().wrapping_add(1);
+        }
+        assert_eq!(next, 0, "{layout:?}");
+
+        for _ in 0..10_000 {
+            let value = rng.random::<u64>() >> rng.random_range(0..64);
+            assert!(layout.range(layout.index(value)).contains(&value));
+        }
+    }
+}
+
+#[test]
+fn test_hdr_error() {
+    let layout = BucketLayout::Hdr { precision: 7 };
+    assert_eq!(layout.num_buckets(), 58 << 7);
+    let mut rng = SmallRng::seed_from_u64(1);
+    for _ in 0..10_000 {
+        let value = rng.random::<u64>() >> rng.random_range(0..64);
+        let range = layout.range(layout.index(value));
+        assert!(range.end() - range.start() <= value >> 7);
+    }
+}
+
+#[test]
+#[should_panic(expected = "precision")]
+fn test_invalid_layout() {
+    AtomicHistogram::<AtomicU64>::new(BucketLayout::Hdr { precision: 17 });
+}
+
+#[test]
+fn test_record_and_quantiles() {
+    let histogram = AtomicHistogram::<AtomicU32>::new(BucketLayout::Linear {
+        min: 0,
+        width: 1,
+        len: 1000,
+    });
+    assert_eq!(histogram.quantile(0.5, Ordering::Relaxed), None);
+    for value in 1..=100 {
+        histogram.record(value, Ordering::Relaxed);
+    }
+    assert_eq!(histogram.total(Ordering::Relaxed), 100);
+    assert_eq!(histogram.quantile(0.0, Ordering::Relaxed), Some(1));
+    assert_eq!(histogram.percentile(50.0, Ordering::Relaxed), Some(50));
+    assert_eq!(histogram.percentile(99.0, Ordering::Relaxed), Some(99));
+    assert_eq!(histogram.quantile(1.0, Ordering::Relaxed), Some(100));
+
+    histogram.record_n(500, 100, Ordering::Relaxed);
+    assert_eq!(histogram.count(500, Ordering::Relaxed), 100);
+    assert_eq!(histogram.percentile(50.0, Ordering::Relaxed), Some(100));
+    assert_eq!(histogram.percentile(51.0, Ordering::Relaxed), Some(500));
+
+    histogram.reset(Ordering::Relaxed);
+    assert_eq!(histogram.total(Ordering::Relaxed), 0);
+}
+
+#[test]
+fn test_record_float() {
+    let histogram = AtomicHistogram::<AtomicU64>::new(BucketLayout::Log2);
+    histogram.record_float(3.7_f64, Ordering::Relaxed);
+    histogram.record_float(3.2_f32, Ordering::Relaxed);
+    histogram.record_float(-1.0_f64, Ordering::Relaxed);
+    histogram.record_float(f64::NAN, Ordering::Relaxed);
+    histogram.record_float(f32::INFINITY, Ordering::Relaxed);
+    histogram.record_float(1e30_f64, Ordering::Relaxed);
+    let counts = histogram.counts(Ordering::Relaxed);
+    assert_eq!(counts[0], 2);
+    assert_eq!(counts[2], 2);
+    assert_eq!(counts[64], 2);
+}
+
+#[test]
+fn test_record_float_decomposition() {
+    let layouts = [
+        BucketLayout::Linear {
+            min: 10,
+            width: 7,
+            len: 100,
+        },
+        BucketLayout::Log2,
+        BucketLayout::Hdr { precision: 0 },
+        BucketLayout::Hdr { precision: 5 },
+        BucketLayout::Hdr { precision: 16 },
+    ];
+    let mut values = vec![0.0, -0.0, 0.5, 1.0, 1.5, 31.9, 32.0, 1e-310, 1e19, 2e19];
+    let mut x = 0.37_f64;
+    while x < 1e21 {
+        values.push(x);
+        values.push(-x);
+        x *= 1.37;
+    }
+    for layout in layouts {
+        let floats = AtomicHistogram::<AtomicU64>::new(layout);
+        let ints = AtomicHistogram::<AtomicU64>::new(layout);
+        for &value in &values {
+            floats.record_float(value, Ordering::Relaxed);
+            floats.record_float(value as f32, Ordering::Relaxed);
+            // the conversion truncates and saturates
+            ints.record(value as u64, Ordering::Relaxed);
+            ints.record((value as f32) as u64, Ordering::Relaxed);
+        }
+        assert_eq!(
+            floats.counts(Ordering::Relaxed),
+            ints.counts(Ordering::Relaxed),
+            "{layout:?}"
+        );
+    }
+}
+
+#[test]
+fn test_concurrent_and_merge() {
+    let layout = BucketLayout::Hdr { precision: 5 };
+    let first = AtomicHistogram::<AtomicU64>::new(layout);
+    let second = AtomicHistogram::<AtomicU64>::new(layout);
+    std::thread::scope(|s| {
+        for t in 0..4 {
+            let histogram = if t % 2 == 0 { &first } else { &second };
+            s.spawn(move || {
+                for value in 0..10_000 {
+                    histogram.record(value, Ordering::Relaxed);
+                }
+            });
+        }
+    });
+    first.merge(&second, Ordering::Relaxed, Ordering::Relaxed);
+    assert_eq!(first.total(Ordering::Relaxed), 40_000);
+    assert_eq!(second.total(Ordering::Relaxed), 20_000);
+    let median = first.percentile(50.0, Ordering::Relaxed).unwrap();
+    assert!(median.abs_diff(5000) <= 5000 >> 5, "{median}");
+}
+
+#[test]
+#[should_panic(expected = "different layouts")]
+fn test_merge_different_layouts() {
+    let first = AtomicHistogram::<AtomicU8>::new(BucketLayout::Log2);
+    let second = AtomicHistogram::<AtomicU8>::new(BucketLayout::Hdr { precision: 0 });
+    first.merge(&second, Ordering::Relaxed, Ordering::Relaxed);
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn test_record_float_half() {
+    use half::f16;
+    for layout in [
+        BucketLayout::Hdr { precision: 3 },
+        BucketLayout::Hdr { precision: 16 },
+    ] {
+        let floats = AtomicHistogram::<AtomicU64>::new(layout);
+        let ints = AtomicHistogram::<AtomicU64>::new(layout);
+        for value in [0.25, 3.5, 100.0, 1000.0, 2049.0, 65504.0] {
+            floats.record_float(f16::from_f64(value), Ordering::Relaxed);
+            ints.record(f16::from_f64(value).to_f64() as u64, Ordering::Relaxed);
+        }
+        floats.record_float(f16::INFINITY, Ordering::Relaxed);
+        ints.record(u64::MAX, Ordering::Relaxed);
+        assert_eq!(
+            floats.counts(Ordering::Relaxed),
+            ints.counts(Ordering::Relaxed),
+            "{layout:?}"
+        );
+    }
+}
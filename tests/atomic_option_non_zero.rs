@@ -0,0 +1,71 @@
+use common_traits::*;
+use core::num::{NonZeroI64, NonZeroU8, NonZeroU32, NonZeroU128};
+use core::sync::atomic::Ordering;
+
+#[test]
+fn test_atomic_option_non_zero() {
+    let one = NonZeroU32::new(1);
+    let two = NonZeroU32::new(2);
+    let x = AtomicOptionNonZero::<NonZeroU32>::default();
+    assert_eq!(x.load(Ordering::Relaxed), None);
+
+    x.store(one, Ordering::Relaxed);
+    assert_eq!(x.load(Ordering::Relaxed), one);
+    assert_eq!(x.swap(two, Ordering::Relaxed), one);
+    assert_eq!(x.take(Ordering::Relaxed), two);
+    assert_eq!(x.take(Ordering::Relaxed), None);
+    assert_eq!(x.replace(one.unwrap(), Ordering::Relaxed), None);
+
+    assert_eq!(
+        x.compare_exchange(None, two, Ordering::Relaxed, Ordering::Relaxed),
+        Err(one)
+    );
+    assert_eq!(
+        x.compare_exchange(one, None, Ordering::Relaxed, Ordering::Relaxed),
+        Ok(one)
+    );
+    assert_eq!(
+        x.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |x| match x {
+            None => Some(two),
+            Some(_) => None,
+        }),
+        Ok(None)
+    );
+    assert_eq!(x.into_inner(), two);
+}
+
+#[test]
+fn test_atomic_option_non_zero_signed_and_wide() {
+    let x = Option::<NonZeroI64>::None.to_atomic();
+    assert_eq!(
+        x.replace(NonZeroI64::new(-1).unwrap(), Ordering::Relaxed),
+        None
+    );
+    assert_eq!(x.load(Ordering::Relaxed), NonZeroI64::new(-1));
+
+    let y = NonZeroU128::new(u128::MAX).to_atomic();
+    assert_eq!(y.take(Ordering::Relaxed), NonZeroU128::new(u128::MAX));
+    assert_eq!(y.into_inner(), None);
+}
+
+#[test]
+fn test_atomic_option_non_zero_slices() {
+    let mut slots = [None, NonZeroU8::new(3), None];
+    let atomic = <Option<NonZeroU8>>::from_mut_slice(&mut slots);
+    assert_eq!(atomic[1].take(Ordering::Relaxed), NonZeroU8::new(3));
+    atomic[2].store(NonZeroU8::new(7), Ordering::Relaxed);
+    let back = <Option<NonZeroU8>>::get_mut_slice(atomic);
+    assert_eq!(back, [None, None, NonZeroU8::new(7)]);
+    assert_eq!(slots, [None, None, NonZeroU8::new(7)]);
+
+    let atomic = <Option<NonZeroU8>>::into_atomic_array([NonZeroU8::new(1), None]);
+    assert_eq!(
+        <Option<NonZeroU8>>::from_atomic_array(atomic),
+        [NonZeroU8::new(1), None]
+    );
+
+    let mut x = AtomicOptionNonZero::<NonZeroU8>::new(None);
+    *x.get_mut() = NonZeroU8::new(5);
+    assert_eq!(x.load(Ordering::Relaxed), NonZeroU8::new(5));
+    assert_eq!(format!("{:?}", x), "Some(5)");
+}
@@ -0,0 +1,106 @@
+use common_traits::*;
+use core::sync::atomic::Ordering;
+
+#[test]
+fn test_atomic_pair() {
+    let x = AtomicPair::new((1_u32, 2_u32));
+    assert_eq!(core::mem::size_of_val(&x), 8);
+    assert_eq!(x.load(Ordering::Relaxed), (1, 2));
+    x.store((3, 4), Ordering::Relaxed);
+    assert_eq!(x.swap((5, 6), Ordering::Relaxed), (3, 4));
+    assert_eq!(
+        x.compare_exchange((5, 7), (0, 0), Ordering::Relaxed, Ordering::Relaxed),
+        Err((5, 6))
+    );
+    assert_eq!(
+        x.compare_exchange((5, 6), (7, 8), Ordering::Relaxed, Ordering::Relaxed),
+        Ok((5, 6))
+    );
+    assert_eq!(
+        x.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |(a, b)| Some((b, a))),
+        Ok((7, 8))
+    );
+    assert_eq!(x.into_inner(), (8, 7));
+}
+
+#[test]
+fn test_atomic_pair_components() {
+    // Negative values must not leak into the other component
+    let x = AtomicPair::new((-1_i16, 1_u16));
+    assert_eq!(core::mem::size_of_val(&x), 4);
+    assert_eq!(x.fetch_add_second(u16::MAX, Ordering::Relaxed), (-1, 1));
+    assert_eq!(x.load(Ordering::Relaxed), (-1, 0));
+    assert_eq!(x.fetch_add_first(-2, Ordering::AcqRel), (-1, 0));
+    assert_eq!(x.load(Ordering::Relaxed), (-3, 0));
+
+    let y = AtomicPair::new((1.5_f32, u8::MAX));
+    assert_eq!(core::mem::size_of_val(&y), 8);
+    assert_eq!(y.fetch_add_first(-3.0, Ordering::Release), (1.5, u8::MAX));
+    assert_eq!(y.fetch_add_second(1, Ordering::Relaxed), (-1.5, u8::MAX));
+    assert_eq!(y.load(Ordering::Relaxed), (-1.5, 0));
+
+    let z = AtomicPair::<i64, f64>::default();
+    assert_eq!(core::mem::size_of_val(&z), 16);
+    z.store((i64::MIN, -0.0), Ordering::Relaxed);
+    let (a, b) = z.load(Ordering::Relaxed);
+    assert_eq!(a, i64::MIN);
+    assert!(b == 0.0 && b.is_sign_negative());
+    assert_eq!(format!("{:?}", z), "(-9223372036854775808, -0.0)");
+}
+
+#[test]
+fn test_atomic_pair_versioned() {
+    let x = AtomicPair::new((10_u32, u32::MAX));
+    let old = x.load(Ordering::Acquire);
+    assert_eq!(
+        x.compare_exchange_versioned(old, 11, Ordering::AcqRel, Ordering::Acquire),
+        Ok((10, u32::MAX))
+    );
+    assert_eq!(x.load(Ordering::Relaxed), (11, 0));
+    x.compare_exchange_versioned((11, 0), 10, Ordering::AcqRel, Ordering::Acquire)
+        .unwrap();
+    assert_eq!(
+        x.compare_exchange_versioned(old, 12, Ordering::AcqRel, Ordering::Acquire),
+        Err((10, 1))
+    );
+}
+
+#[cfg(not(miri))]
+#[test]
+fn test_atomic_pair_concurrent() {
+    let x = AtomicPair::new((0_u32, 0_u32));
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..1000 {
+                    let mut current = x.load(Ordering::Acquire);
+                    while let Err(actual) = x.compare_exchange_versioned(
+                        current,
+                        current.0 + 2,
+                        Ordering::AcqRel,
+                        Ordering::Acquire,
+                    ) {
+                        current = actual;
+                    }
+                }
+            });
+        }
+    });
+    assert_eq!(x.into_inner(), (8000, 4000));
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn test_atomic_pair_half() {
+    use half::{bf16, f16};
+    let x = AtomicPair::new((f16::ONE, bf16::NEG_ONE));
+    assert_eq!(core::mem::size_of_val(&x), 4);
+    assert_eq!(
+        x.fetch_add_first(f16::ONE, Ordering::Relaxed),
+        (f16::ONE, bf16::NEG_ONE)
+    );
+    assert_eq!(
+        x.load(Ordering::Relaxed),
+        (f16::from_f32(2.0), bf16::NEG_ONE)
+    );
+}
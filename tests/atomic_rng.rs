@@ -0,0 +1,71 @@
+use common_traits::*;
+use std::collections::HashSet;
+
+#[test]
+fn test_split_mix_64() {
+    // reference values of the C implementation with seed 1234567
+    let mut rng = SplitMix64::new(1234567);
+    assert_eq!(rng.next::<u64>(), 6457827717110365317);
+    assert_eq!(rng.next::<u64>(), 3203168211198807973);
+    assert_eq!(rng.next::<u64>(), 9817491932198370423);
+
+    let shared = AtomicRng::new(1234567);
+    assert_eq!(shared.next::<u64>(), 6457827717110365317);
+    let mut shared_ref = &shared;
+    assert_eq!(
+        <&AtomicRng as RngNext<u64>>::next_inner(&mut shared_ref),
+        3203168211198807973
+    );
+}
+
+fn check_unit<T: PartialOrd + From<f32> + Copy>(values: impl Iterator<Item = T>) {
+    let mut low = 0;
+    let mut count = 0;
+    for value in values {
+        assert!(value >= T::from(0.0) && value < T::from(1.0));
+        if value < T::from(0.5) {
+            low += 1;
+        }
+        count += 1;
+    }
+    assert!(low > count / 3 && low < 2 * count / 3);
+}
+
+#[test]
+fn test_types() {
+    let rng = AtomicRng::new(0);
+    check_unit((0..1000).map(|_| rng.next::<f64>()));
+    check_unit((0..1000).map(|_| rng.next::<f32>()));
+
+    let bytes: HashSet<u8> = (0..10_000).map(|_| rng.next()).collect();
+    assert_eq!(bytes.len(), 256);
+    let signed: HashSet<i8> = (0..10_000).map(|_| rng.next()).collect();
+    assert_eq!(signed.len(), 256);
+    let bools: HashSet<bool> = (0..100).map(|_| rng.next()).collect();
+    assert_eq!(bools.len(), 2);
+
+    let _: (u16, u32, usize, u128) = (rng.next(), rng.next(), rng.next(), rng.next());
+    let _: (i16, i32, i64, isize, i128) =
+        (rng.next(), rng.next(), rng.next(), rng.next(), rng.next());
+}
+
+#[test]
+fn test_concurrent() {
+    let rng = AtomicRng::new(7);
+    let values: Vec<Vec<u64>> = std::thread::scope(|s| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| s.spawn(|| (0..10_000).map(|_| rng.next::<u64>()).collect()))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    let distinct: HashSet<u64> = values.into_iter().flatten().collect();
+    assert_eq!(distinct.len(), 40_000);
+}
+
+#[test]
+fn test_fork() {
+    let rng = AtomicRng::new(3);
+    let mut first: SplitMix64 = rng.fork();
+    let mut second: SplitMix64 = rng.fork();
+    assert_ne!(first.next::<u64>(), second.next::<u64>());
+}
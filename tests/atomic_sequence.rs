@@ -0,0 +1,88 @@
+use common_traits::*;
+use core::sync::atomic::{AtomicU8, AtomicU32, AtomicUsize, Ordering};
+
+fn sum<S: Sequence<Item = usize>>(sequence: &S) -> usize {
+    sequence.iter().sum()
+}
+
+fn fill<S: AtomicSequence<Item = AtomicUsize> + Sync + ?Sized>(sequence: &S) {
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for i in 0..sequence.len() {
+                    sequence.fetch_add_at(i, i, Ordering::Relaxed).unwrap();
+                }
+            });
+        }
+    });
+}
+
+#[test]
+fn test_atomic_sequence_slice() {
+    let data = [const { AtomicU32::new(0) }; 4];
+    let slice: &[AtomicU32] = &data;
+    assert_eq!(AtomicSequence::len(slice), 4);
+    assert!(!AtomicSequence::is_empty(slice));
+
+    slice.set(1, 5, Ordering::Relaxed).unwrap();
+    assert_eq!(AtomicSequence::get(slice, 1, Ordering::Relaxed).unwrap(), 5);
+    assert!(slice.set(4, 5, Ordering::Relaxed).is_err());
+    assert!(AtomicSequence::get(slice, 4, Ordering::Relaxed).is_err());
+
+    assert_eq!(slice.fetch_add_at(1, 2, Ordering::Relaxed).unwrap(), 5);
+    assert_eq!(
+        slice
+            .compare_exchange_at(1, 7, 8, Ordering::Relaxed, Ordering::Relaxed)
+            .unwrap(),
+        Ok(7)
+    );
+    assert_eq!(
+        slice
+            .compare_exchange_at(1, 7, 9, Ordering::Relaxed, Ordering::Relaxed)
+            .unwrap(),
+        Err(8)
+    );
+    assert!(
+        slice
+            .compare_exchange_at(9, 7, 9, Ordering::Relaxed, Ordering::Relaxed)
+            .is_err()
+    );
+    assert_eq!(
+        unsafe { AtomicSequence::get_unchecked(slice, 1, Ordering::Relaxed) },
+        8
+    );
+    unsafe { slice.set_unchecked(0, 3, Ordering::Relaxed) };
+
+    let view = WithOrdering::new(slice, Ordering::Acquire);
+    assert_eq!(view.order(), Ordering::Acquire);
+    assert_eq!(view.iter().collect::<Vec<_>>(), vec![3, 8, 0, 0]);
+    assert_eq!(view.iter().len(), 4);
+    assert_eq!(Sequence::get(&view, 1).unwrap(), 8);
+    assert!(Sequence::get(&view, 4).is_err());
+
+    // Arrays are atomic sequences, too
+    assert_eq!(data.fetch_add_at(3, 1, Ordering::Relaxed).unwrap(), 0);
+}
+
+#[test]
+fn test_atomic_sequence_vec_and_box() {
+    let vec: Vec<AtomicUsize> = (0..100).map(|_| AtomicUsize::new(0)).collect();
+    fill(&vec);
+    assert_eq!(sum(&WithOrdering::new(&vec, Ordering::Relaxed)), 4 * 4950);
+
+    let boxed: Box<[AtomicUsize]> = (0..10).map(|_| AtomicUsize::new(0)).collect();
+    fill(&boxed);
+    assert_eq!(sum(&WithOrdering::new(boxed, Ordering::Relaxed)), 4 * 45);
+}
+
+#[test]
+fn test_atomic_sequence_from_mut_slice() {
+    let mut data = vec![0_u8; 8];
+    {
+        let atomic = <AtomicU8 as Atomic>::from_mut_slice(&mut data);
+        for i in 0..atomic.len() {
+            atomic.set(i, i as u8, Ordering::Relaxed).unwrap();
+        }
+    }
+    assert_eq!(data, vec![0, 1, 2, 3, 4, 5, 6, 7]);
+}
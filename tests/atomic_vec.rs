@@ -0,0 +1,64 @@
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use common_traits::sync::AtomicU32;
+use common_traits::*;
+use core::sync::atomic::Ordering;
+use std::sync::Arc;
+
+#[test]
+fn test_into_atomic_vec() {
+    let mut data = Vec::with_capacity(10);
+    data.extend(0_u32..8);
+    let ptr = data.as_ptr() as usize;
+
+    let atomic: Vec<AtomicU32> = u32::into_atomic_vec(data);
+    assert_eq!(atomic.as_ptr() as usize, ptr);
+    assert_eq!(atomic.len(), 8);
+    assert_eq!(atomic.capacity(), 10);
+    std::thread::scope(|s| {
+        for value in &atomic {
+            s.spawn(|| value.fetch_add(1, Ordering::Relaxed));
+        }
+    });
+
+    let data = u32::from_atomic_vec(atomic);
+    assert_eq!(data.as_ptr() as usize, ptr);
+    assert_eq!(data.capacity(), 10);
+    assert_eq!(data, (1..9).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_into_atomic_boxed_slice() {
+    let data: Box<[f64]> = vec![1.0, 2.0, 3.0].into_boxed_slice();
+    let ptr = data.as_ptr() as usize;
+
+    let atomic = f64::into_atomic_boxed_slice(data);
+    assert_eq!(atomic.as_ptr() as usize, ptr);
+    atomic[1].store(5.0, Ordering::Relaxed);
+
+    let data = f64::from_atomic_boxed_slice(atomic);
+    assert_eq!(data.as_ptr() as usize, ptr);
+    assert_eq!(&*data, &[1.0, 5.0, 3.0]);
+}
+
+#[test]
+fn test_into_atomic_vec_empty() {
+    let atomic = u8::into_atomic_vec(Vec::new());
+    assert!(atomic.is_empty());
+    assert!(u8::from_atomic_vec(atomic).is_empty());
+    let atomic = u8::into_atomic_boxed_slice(Box::default());
+    assert!(u8::from_atomic_boxed_slice(atomic).is_empty());
+}
+
+#[test]
+fn test_into_atomic_arc() {
+    let atomic: Arc<[AtomicU32]> = u32::into_atomic_arc(vec![0; 4]);
+    std::thread::scope(|s| {
+        for i in 0..4 {
+            let atomic = atomic.clone();
+            s.spawn(move || atomic[i].store(i as u32 * 2, Ordering::Relaxed));
+        }
+    });
+    let values: Vec<u32> = atomic.iter().map(|x| x.load(Ordering::Relaxed)).collect();
+    assert_eq!(values, [0, 2, 4, 6]);
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "std")]
+
+use common_traits::*;
+use core::sync::atomic::{AtomicI32, AtomicU32, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+#[test]
+fn test_atomic_wait_timeout() {
+    let x = AtomicI32::new(-1);
+    let start = Instant::now();
+    assert!(!x.wait(-1, Some(Duration::from_millis(20))));
+    assert!(start.elapsed() >= Duration::from_millis(20));
+    // The value is different, so the call returns immediately
+    assert!(x.wait(0, None));
+    // Nobody is waiting
+    x.notify_one();
+    x.notify_all();
+
+    let x = AtomicOptionNonZero::<core::num::NonZeroU32>::new(core::num::NonZeroU32::new(1));
+    assert!(x.wait(None, None));
+    assert!(!x.wait(core::num::NonZeroU32::new(1), Some(Duration::ZERO)));
+
+    let y = AtomicF32::new(-0.0);
+    assert!(y.wait(0.0, None));
+    assert!(!y.wait(-0.0, Some(Duration::from_millis(1))));
+}
+
+#[cfg(not(miri))]
+#[test]
+fn test_atomic_wait_latch() {
+    let latch = AtomicU32::new(0);
+    let woken = AtomicUsize::new(0);
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                while latch.load(Ordering::Acquire) == 0 {
+                    latch.wait(0, None);
+                }
+                woken.fetch_add(1, Ordering::Relaxed);
+            });
+        }
+        std::thread::sleep(Duration::from_millis(10));
+        latch.store(1, Ordering::Release);
+        latch.notify_all();
+    });
+    assert_eq!(woken.into_inner(), 4);
+}
+
+#[cfg(not(miri))]
+#[test]
+fn test_atomic_wait_ping_pong() {
+    let turn = AtomicU32::new(0);
+    std::thread::scope(|s| {
+        for player in 0..2 {
+            let turn = &turn;
+            s.spawn(move || {
+                for round in 0..100 {
+                    let mine = 2 * round + player;
+                    loop {
+                        let current = turn.load(Ordering::Acquire);
+                        if current == mine {
+                            break;
+                        }
+                        turn.wait(current, None);
+                    }
+                    turn.store(mine + 1, Ordering::Release);
+                    turn.notify_one();
+                }
+            });
+        }
+    });
+    assert_eq!(turn.into_inner(), 200);
+}
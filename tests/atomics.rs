@@ -61,11 +61,199 @@ where
     assert_eq!(x.load(Ordering::Relaxed), 30_usize.to());
 }
 
+fn test_atomic_integer<F: AtomicInteger>()
+where
+    usize: To<F::NonAtomicType>,
+    F::NonAtomicType: Debug + Integer + FiniteRangeNumber + AsBytes,
+{
+    let (set, fetch) = (Ordering::AcqRel, Ordering::Acquire);
+    let max = F::NonAtomicType::MAX;
+    let x = F::new(max - 1_usize.to());
+    assert_eq!(
+        x.fetch_checked_add(1_usize.to(), set, fetch),
+        Ok(max - 1_usize.to())
+    );
+    assert_eq!(x.fetch_checked_add(1_usize.to(), set, fetch), Err(max));
+    assert_eq!(x.fetch_checked_mul(2_usize.to(), set, fetch), Err(max));
+    assert_eq!(x.load(Ordering::Relaxed), max);
+
+    x.store(F::NonAtomicType::MIN + 1_usize.to(), Ordering::Relaxed);
+    assert_eq!(
+        x.fetch_checked_sub(1_usize.to(), set, fetch),
+        Ok(F::NonAtomicType::MIN + 1_usize.to())
+    );
+    assert_eq!(
+        x.fetch_checked_sub(1_usize.to(), set, fetch),
+        Err(F::NonAtomicType::MIN)
+    );
+
+    x.store(3_usize.to(), Ordering::Relaxed);
+    assert_eq!(
+        x.fetch_checked_mul(5_usize.to(), set, fetch),
+        Ok(3_usize.to())
+    );
+    assert_eq!(
+        x.fetch_wrapping_mul(2_usize.to(), set, fetch),
+        15_usize.to()
+    );
+    assert_eq!(x.load(Ordering::Relaxed), 30_usize.to());
+
+    x.store(max, Ordering::Relaxed);
+    x.fetch_wrapping_mul(2_usize.to(), set, fetch);
+    assert_eq!(x.load(Ordering::Relaxed), max.wrapping_mul(2_usize.to()));
+
+    let bits = F::NonAtomicType::BITS as u32;
+    x.store(5_usize.to(), Ordering::Relaxed);
+    assert_eq!(x.fetch_wrapping_shl(bits + 2, set, fetch), 5_usize.to());
+    assert_eq!(x.load(Ordering::Relaxed), 20_usize.to());
+    assert_eq!(x.fetch_wrapping_shr(2, set, fetch), 20_usize.to());
+    assert_eq!(x.load(Ordering::Relaxed), 5_usize.to());
+
+    let five: F::NonAtomicType = 5_usize.to();
+    assert_eq!(x.fetch_rotate_right(1, set, fetch), five);
+    assert_eq!(x.load(Ordering::Relaxed), five.rotate_right(1));
+    assert_eq!(x.fetch_rotate_left(1, set, fetch), five.rotate_right(1));
+    assert_eq!(x.load(Ordering::Relaxed), 5_usize.to());
+}
+
+fn test_atomic_unsigned_int<F: AtomicUnsignedInt>()
+where
+    usize: To<F::NonAtomicType>,
+    isize: To<<F::NonAtomicType as UnsignedInt>::SignedInt>,
+    F::NonAtomicType: Debug + UnsignedInt + FiniteRangeNumber,
+{
+    let (set, fetch) = (Ordering::AcqRel, Ordering::Acquire);
+    let x = F::new(10_usize.to());
+    assert_eq!(
+        x.fetch_add_signed((-3_isize).to(), Ordering::Relaxed),
+        10_usize.to()
+    );
+    assert_eq!(
+        x.fetch_add_signed(5_isize.to(), Ordering::Relaxed),
+        7_usize.to()
+    );
+    assert_eq!(x.load(Ordering::Relaxed), 12_usize.to());
+
+    assert_eq!(
+        x.fetch_checked_add_signed((-12_isize).to(), set, fetch),
+        Ok(12_usize.to())
+    );
+    assert_eq!(
+        x.fetch_checked_add_signed((-1_isize).to(), set, fetch),
+        Err(0_usize.to())
+    );
+    assert_eq!(
+        x.fetch_add_signed((-1_isize).to(), Ordering::Relaxed),
+        0_usize.to()
+    );
+    assert_eq!(x.load(Ordering::Relaxed), F::NonAtomicType::MAX);
+}
+
+fn test_atomic_float<F: AtomicFloat>()
+where
+    usize: To<F::NonAtomicType>,
+    F::NonAtomicType: Debug + Float,
+{
+    let x = F::new(4_usize.to());
+    assert!(x.is_finite(Ordering::Relaxed));
+    assert!(x.is_sign_positive(Ordering::Relaxed));
+    assert_eq!(x.classify(Ordering::Relaxed), core::num::FpCategory::Normal);
+
+    assert_eq!(
+        x.fetch_sqrt(Ordering::Relaxed, Ordering::Relaxed),
+        4_usize.to()
+    );
+    assert_eq!(x.load(Ordering::Relaxed), 2_usize.to());
+    assert_eq!(
+        x.powi_and_get(3, Ordering::AcqRel, Ordering::Acquire),
+        8_usize.to()
+    );
+    assert_eq!(
+        x.fetch_recip(Ordering::Release, Ordering::Relaxed),
+        8_usize.to()
+    );
+    assert_eq!(
+        x.recip_and_get(Ordering::SeqCst, Ordering::SeqCst),
+        8_usize.to()
+    );
+    assert_eq!(
+        x.fetch_copysign(-F::NonAtomicType::ONE, Ordering::Relaxed, Ordering::Relaxed),
+        8_usize.to()
+    );
+    assert!(x.is_sign_negative(Ordering::Relaxed));
+    assert_eq!(
+        x.signum_and_get(Ordering::Relaxed, Ordering::Relaxed),
+        -F::NonAtomicType::ONE
+    );
+    assert_eq!(
+        x.fetch_sqrt(Ordering::Relaxed, Ordering::Relaxed),
+        -F::NonAtomicType::ONE
+    );
+    assert!(x.is_nan(Ordering::Relaxed));
+}
+
+fn test_atomic_float_min_max<F: AtomicFloat>()
+where
+    F::NonAtomicType: Debug + Float,
+{
+    let zero = F::NonAtomicType::ZERO;
+    let one = F::NonAtomicType::ONE;
+    let nan = F::NonAtomicType::NAN;
+    let same = |a: F::NonAtomicType, b: F::NonAtomicType| a.total_cmp(&b).is_eq();
+
+    // NaN-propagating
+    let x = F::new(-zero);
+    assert!(x.fetch_maximum(zero, Ordering::Relaxed).is_sign_negative());
+    assert!(x.is_sign_positive(Ordering::Relaxed));
+    x.fetch_minimum(-zero, Ordering::AcqRel);
+    assert!(x.is_sign_negative(Ordering::Relaxed));
+    x.fetch_maximum(nan, Ordering::Release);
+    assert!(x.is_nan(Ordering::Relaxed));
+    x.fetch_minimum(-one, Ordering::Relaxed);
+    assert!(x.is_nan(Ordering::Relaxed));
+
+    // NaN-ignoring
+    let x = F::new(nan);
+    assert!(x.fetch_maximum_number(-one, Ordering::Relaxed).is_nan());
+    assert_eq!(x.load(Ordering::Relaxed), -one);
+    x.fetch_maximum_number(nan, Ordering::Relaxed);
+    assert_eq!(x.load(Ordering::Relaxed), -one);
+    x.fetch_minimum_number(F::NonAtomicType::NEG_INFINITY, Ordering::Relaxed);
+    assert_eq!(x.load(Ordering::Relaxed), F::NonAtomicType::NEG_INFINITY);
+    x.store(zero, Ordering::Relaxed);
+    x.fetch_minimum_number(-zero, Ordering::Relaxed);
+    assert!(x.is_sign_negative(Ordering::Relaxed));
+
+    // Total order: -NaN < -inf < -1 < -0 < +0 < 1 < inf < NaN
+    let values = [
+        -nan,
+        F::NonAtomicType::NEG_INFINITY,
+        -one,
+        -zero,
+        zero,
+        one,
+        F::NonAtomicType::INFINITY,
+        nan,
+    ];
+    for (i, a) in values.into_iter().enumerate() {
+        for (j, b) in values.into_iter().enumerate() {
+            let x = F::new(a);
+            assert!(same(x.fetch_max_total(b, Ordering::Relaxed), a));
+            assert!(same(x.load(Ordering::Relaxed), values[Ord::max(i, j)]));
+            let x = F::new(a);
+            assert!(same(x.fetch_min_total(b, Ordering::SeqCst), a));
+            assert!(same(x.load(Ordering::Relaxed), values[Ord::min(i, j)]));
+        }
+    }
+}
+
 #[cfg(all(feature = "half", not(miri)))]
 #[test]
 fn test_atomic_float_bf16() {
     test_atomic::<AtomicBF16>();
     test_atomic_number::<AtomicBF16>();
+    test_atomic_float::<AtomicBF16>();
+    test_atomic_float_min_max::<AtomicBF16>();
 }
 
 #[cfg(all(feature = "half", not(miri)))]
@@ -73,76 +261,147 @@ fn test_atomic_float_bf16() {
 fn test_atomic_float_f16() {
     test_atomic::<AtomicF16>();
     test_atomic_number::<AtomicF16>();
+    test_atomic_float::<AtomicF16>();
+    test_atomic_float_min_max::<AtomicF16>();
 }
 
 #[test]
 fn test_atomic_float_f32() {
     test_atomic::<AtomicF32>();
     test_atomic_number::<AtomicF32>();
+    test_atomic_float::<AtomicF32>();
+    test_atomic_float_min_max::<AtomicF32>();
 }
 
 #[test]
 fn test_atomic_float_f64() {
     test_atomic::<AtomicF64>();
     test_atomic_number::<AtomicF64>();
+    test_atomic_float::<AtomicF64>();
+    test_atomic_float_min_max::<AtomicF64>();
 }
 
 #[test]
 fn test_atomic_int_i8() {
     test_atomic::<AtomicI8>();
     test_atomic_number::<AtomicI8>();
+    test_atomic_integer::<AtomicI8>();
 }
 
 #[test]
 fn test_atomic_int_i16() {
     test_atomic::<AtomicI16>();
     test_atomic_number::<AtomicI16>();
+    test_atomic_integer::<AtomicI16>();
 }
 
 #[test]
 fn test_atomic_int_i32() {
     test_atomic::<AtomicI32>();
     test_atomic_number::<AtomicI32>();
+    test_atomic_integer::<AtomicI32>();
 }
 
 #[test]
 fn test_atomic_int_i64() {
     test_atomic::<AtomicI64>();
     test_atomic_number::<AtomicI64>();
+    test_atomic_integer::<AtomicI64>();
 }
 
 #[test]
 fn test_atomic_int_isize() {
     test_atomic::<AtomicIsize>();
     test_atomic_number::<AtomicIsize>();
+    test_atomic_integer::<AtomicIsize>();
+}
+
+#[test]
+fn test_atomic_int_i128() {
+    test_atomic::<AtomicI128>();
+    test_atomic_number::<AtomicI128>();
+    test_atomic_integer::<AtomicI128>();
 }
 
 #[test]
 fn test_atomic_uint_u8() {
     test_atomic::<AtomicU8>();
     test_atomic_number::<AtomicU8>();
+    test_atomic_integer::<AtomicU8>();
+    test_atomic_unsigned_int::<AtomicU8>();
 }
 
 #[test]
 fn test_atomic_uint_u16() {
     test_atomic::<AtomicU16>();
     test_atomic_number::<AtomicU16>();
+    test_atomic_integer::<AtomicU16>();
+    test_atomic_unsigned_int::<AtomicU16>();
 }
 
 #[test]
 fn test_atomic_uint_u32() {
     test_atomic::<AtomicU32>();
     test_atomic_number::<AtomicU32>();
+    test_atomic_integer::<AtomicU32>();
+    test_atomic_unsigned_int::<AtomicU32>();
 }
 
 #[test]
 fn test_atomic_uint_u64() {
     test_atomic::<AtomicU64>();
     test_atomic_number::<AtomicU64>();
+    test_atomic_integer::<AtomicU64>();
+    test_atomic_unsigned_int::<AtomicU64>();
 }
 
 #[test]
 fn test_atomic_uint_usize() {
     test_atomic::<AtomicUsize>();
     test_atomic_number::<AtomicUsize>();
+    test_atomic_integer::<AtomicUsize>();
+    test_atomic_unsigned_int::<AtomicUsize>();
+}
+
+#[test]
+fn test_atomic_uint_u128() {
+    test_atomic::<AtomicU128>();
+    test_atomic_number::<AtomicU128>();
+    test_atomic_integer::<AtomicU128>();
+    test_atomic_unsigned_int::<AtomicU128>();
+}
+
+#[test]
+fn test_atomic_128_wide_values() {
+    let x = AtomicU128::new(u64::MAX as u128);
+    assert_eq!(x.fetch_add(1, Ordering::Relaxed), u64::MAX as u128);
+    assert_eq!(x.load(Ordering::Relaxed), 1 << 64);
+    assert_eq!(x.fetch_xor(u128::MAX, Ordering::Relaxed), 1 << 64);
+    assert_eq!(x.load(Ordering::Relaxed), !(1 << 64));
+
+    let y = AtomicI128::new(-1);
+    assert_eq!(y.fetch_min(i128::MIN, Ordering::Relaxed), -1);
+    assert_eq!(y.fetch_max(i128::MAX, Ordering::Relaxed), i128::MIN);
+    assert_eq!(y.into_inner(), i128::MAX);
+
+    let mut data = [1_u128 << 100, 2];
+    let atomic = <u128 as IntoAtomic>::from_mut_slice(&mut data);
+    atomic[0].fetch_or(1, Ordering::Relaxed);
+    assert_eq!(data, [(1 << 100) | 1, 2]);
+}
+
+#[cfg(not(miri))]
+#[test]
+fn test_atomic_128_concurrent() {
+    let x = AtomicU128::new(0);
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..10_000 {
+                    x.fetch_add((1 << 64) | 1, Ordering::Relaxed);
+                }
+            });
+        }
+    });
+    assert_eq!(x.into_inner(), (40_000 << 64) | 40_000);
 }
@@ -0,0 +1,74 @@
+use common_traits::*;
+use core::sync::atomic::Ordering;
+
+#[test]
+fn test_compensated_sum() {
+    // A naive sum of these values is 0.0
+    let values = [1.0_f64, 1E100, 1.0, -1E100];
+    assert_eq!(values.iter().sum::<f64>(), 0.0);
+    assert_eq!(
+        values.into_iter().collect::<CompensatedSum<_>>().value(),
+        2.0
+    );
+
+    let mut sum = CompensatedSum::new(0.5_f32);
+    sum += 1E8;
+    sum.extend([0.25, -1E8]);
+    assert_eq!(sum.value(), 0.75);
+    assert_eq!(CompensatedSum::<f32>::default().value(), 0.0);
+}
+
+#[test]
+fn test_atomic_compensated_sum() {
+    let sum = AtomicCompensatedSum::new(1.0_f32);
+    assert_eq!(core::mem::size_of_val(&sum), 8);
+    sum.add(1E10, Ordering::Relaxed);
+    sum.add(1.0, Ordering::AcqRel);
+    sum.add(-1E10, Ordering::Release);
+    assert_eq!(sum.load(Ordering::Acquire), 2.0);
+    assert_eq!(
+        sum.load_compensated(Ordering::Relaxed).value(),
+        sum.load(Ordering::Relaxed)
+    );
+    assert_eq!(sum.swap(3.0, Ordering::Relaxed), 2.0);
+    assert_eq!(sum.into_inner(), 3.0);
+
+    let sum = AtomicCompensatedSum::from(CompensatedSum::from_iter([1.0_f64, 1E100, 1.0]));
+    assert_eq!(core::mem::size_of_val(&sum), 16);
+    sum.add(-1E100, Ordering::Relaxed);
+    assert_eq!(sum.load(Ordering::Relaxed), 2.0);
+    assert_eq!(AtomicCompensatedSum::<f64>::default().into_inner(), 0.0);
+}
+
+#[cfg(not(miri))]
+#[test]
+fn test_atomic_compensated_sum_concurrent() {
+    let sum = AtomicCompensatedSum::<f64>::default();
+    let naive = AtomicF64::new(0.0);
+    std::thread::scope(|s| {
+        for _ in 0..8 {
+            s.spawn(|| {
+                for _ in 0..10_000 {
+                    sum.add(0.1, Ordering::Relaxed);
+                    naive.fetch_add(0.1, Ordering::Relaxed);
+                }
+            });
+        }
+    });
+    let sum = sum.load(Ordering::Relaxed);
+    let naive = naive.load(Ordering::Relaxed);
+    assert!((sum - 8000.0).abs() <= 1E-12);
+    assert!((sum - 8000.0).abs() < (naive - 8000.0).abs());
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn test_atomic_compensated_sum_half() {
+    use half::f16;
+    let sum = AtomicCompensatedSum::new(f16::ONE);
+    assert_eq!(core::mem::size_of_val(&sum), 4);
+    for _ in 0..8 {
+        sum.add(f16::from_f32(0.0001), Ordering::Relaxed);
+    }
+    assert!((sum.load(Ordering::Relaxed).to_f32() - 1.0008).abs() < 1E-3);
+}
@@ -0,0 +1,91 @@
+use common_traits::*;
+
+fn test_convert_slice<A: ConvertSlice<B> + Copy, B: Copy + Default + core::fmt::Debug>(
+    src: &[A],
+    eq: impl Fn(B, B) -> bool,
+) {
+    let mut dst = vec![B::default(); src.len()];
+    A::convert_slice(src, &mut dst);
+    for i in 0..src.len() {
+        let expected = <A as To<B>>::to(src[i]);
+        assert!(eq(expected, dst[i]), "{:?} != {:?}", expected, dst[i]);
+    }
+}
+
+fn floats() -> Vec<f32> {
+    let mut values = vec![
+        0.0,
+        -0.0,
+        1.0,
+        -1.5,
+        f32::MAX,
+        f32::MIN,
+        f32::MIN_POSITIVE,
+        1E-40,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::NAN,
+        3E9,
+        -3E9,
+        1.0 + f32::EPSILON,
+    ];
+    values.extend((0..1000).map(|i| (i as f32 - 500.0) * 1.37));
+    values.extend((0..100).map(|i| f32::from_bits(0x3F80_8000 + i)));
+    values
+}
+
+#[test]
+fn test_convert_slice_hot_pairs() {
+    let bytes: Vec<u8> = (0..=255).collect();
+    test_convert_slice::<u8, f32>(&bytes, |a, b| a == b);
+    let ints: Vec<i32> = (-1000..1000)
+        .map(|i| i * 1234567)
+        .chain([i32::MIN, i32::MAX])
+        .collect();
+    test_convert_slice::<i32, f32>(&ints, |a, b| a == b);
+    test_convert_slice::<f32, i32>(&floats(), |a, b| a == b);
+    test_convert_slice::<u64, f64>(&[0, 1, u64::MAX], |a, b| a == b);
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn test_convert_slice_half() {
+    use half::{bf16, f16};
+    let same = |a: f32, b: f32| a.to_bits() == b.to_bits();
+    let floats = floats();
+    test_convert_slice::<f32, bf16>(&floats, |a, b| a.to_bits() == b.to_bits());
+    test_convert_slice::<f32, f16>(&floats, |a, b| a.to_bits() == b.to_bits());
+    let doubles: Vec<f64> = floats.iter().map(|x| x as f64).collect();
+    test_convert_slice::<f64, bf16>(&doubles, |a, b| a.to_bits() == b.to_bits());
+    test_convert_slice::<f64, f16>(&doubles, |a, b| a.to_bits() == b.to_bits());
+
+    let bits: Vec<u16> = (0..=u16::MAX).collect();
+    let bf16s: Vec<bf16> = bits.iter().map(bf16::from_bits).collect();
+    let f16s: Vec<f16> = bits.iter().map(f16::from_bits).collect();
+    test_convert_slice::<bf16, f32>(&bf16s, same);
+    test_convert_slice::<f16, f32>(&f16s, same);
+    test_convert_slice::<u8, f16>(&[0, 1, 255], |a, b| a == b);
+}
+
+#[test]
+#[should_panic]
+fn test_convert_slice_len_mismatch() {
+    let mut dst = [0.0_f32; 2];
+    u8::convert_slice(&[1, 2, 3], &mut dst);
+}
+
+#[test]
+fn test_convert_sequence() {
+    let src = vec![1_u32, 2, u32::MAX];
+    let mut dst: Vec<f64> = Vec::new();
+    src.convert_into(&mut dst);
+    assert_eq!(dst, vec![1.0, 2.0, u32::MAX as f64]);
+
+    let mut dst: Vec<u64> = vec![7];
+    src.upcast_into(&mut dst);
+    assert_eq!(dst, vec![7, 1, 2, u32::MAX as u64]);
+
+    let mut dst: Vec<u8> = Vec::new();
+    src.as_slice().downcast_into(&mut dst);
+    assert_eq!(dst, vec![1, 2, u8::MAX]);
+}
@@ -0,0 +1,109 @@
+#![cfg(loom)]
+
+use common_traits::sync::loom;
+use common_traits::sync::{AtomicU32, AtomicU64};
+use common_traits::*;
+use core::sync::atomic::Ordering;
+use loom::sync::Arc;
+use loom::thread;
+
+/// A lock-free increment written against [`Atomic`] only.
+fn increment<A: Atomic>(atomic: &A)
+where
+    A::NonAtomicType: Number,
+{
+    let mut current = atomic.load(Ordering::Relaxed);
+    while let Err(actual) = atomic.compare_exchange_weak(
+        current,
+        current + A::NonAtomicType::ONE,
+        Ordering::AcqRel,
+        Ordering::Relaxed,
+    ) {
+        current = actual;
+    }
+}
+
+#[test]
+fn test_loom_atomic() {
+    loom::model(|| {
+        let counter = Arc::new(AtomicU64::new(0));
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let counter = counter.clone();
+                thread::spawn(move || increment(&*counter))
+            })
+            .collect();
+        handles.into_iter().for_each(|h| h.join().unwrap());
+        assert_eq!(counter.load(Ordering::Relaxed), 2);
+    });
+}
+
+#[test]
+fn test_loom_atomic_number() {
+    loom::model(|| {
+        let sum = Arc::new(AtomicF64::new(0.0));
+        let max = Arc::new(AtomicF64::new(0.0));
+        let handles: Vec<_> = [1.0, 2.0]
+            .into_iter()
+            .map(|value| {
+                let (sum, max) = (sum.clone(), max.clone());
+                thread::spawn(move || {
+                    AtomicNumber::fetch_add(&*sum, value, Ordering::Relaxed);
+                    AtomicNumber::fetch_max(&*max, value, Ordering::Relaxed);
+                })
+            })
+            .collect();
+        handles.into_iter().for_each(|h| h.join().unwrap());
+        assert_eq!(sum.load(Ordering::Relaxed), 3.0);
+        assert_eq!(max.load(Ordering::Relaxed), 2.0);
+    });
+}
+
+#[test]
+fn test_loom_atomic_pair() {
+    loom::model(|| {
+        let pair = Arc::new(AtomicPair::<u32, u32>::new((0, 0)));
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let pair = pair.clone();
+                thread::spawn(move || {
+                    pair.fetch_update(Ordering::AcqRel, Ordering::Acquire, |(a, b)| {
+                        Some((a + 1, b + 2))
+                    })
+                    .unwrap();
+                })
+            })
+            .collect();
+        let (a, b) = pair.load(Ordering::Acquire);
+        assert_eq!(b, 2 * a);
+        handles.into_iter().for_each(|h| h.join().unwrap());
+        assert_eq!(pair.load(Ordering::Acquire), (2, 4));
+    });
+}
+
+#[test]
+fn test_loom_atomic_wait() {
+    loom::model(|| {
+        let latch = Arc::new(AtomicU32::new(0));
+        let handle = {
+            let latch = latch.clone();
+            thread::spawn(move || {
+                latch.store(1, Ordering::Release);
+                latch.notify_all();
+            })
+        };
+        while latch.load(Ordering::Acquire) == 0 {
+            latch.wait(0, None);
+        }
+        handle.join().unwrap();
+    });
+}
+
+#[test]
+#[should_panic(expected = "loom")]
+fn test_loom_get_mut() {
+    loom::model(|| {
+        let mut atomic = AtomicU64::new(0);
+        *atomic.get_mut() = 1;
+    });
+}
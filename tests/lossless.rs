@@ -0,0 +1,38 @@
+use common_traits::*;
+
+fn widen<T: LosslessInto<i128> + UpcastableInto<i128> + Copy>(x: T) -> (i128, i128) {
+    (x.lossless(), x.upcast())
+}
+
+#[test]
+fn test_lossless() {
+    assert_eq!(widen(u64::MAX), (u64::MAX as i128, u64::MAX as i128));
+    assert_eq!(widen(usize::MAX), (usize::MAX as i128, usize::MAX as i128));
+    assert_eq!(widen(isize::MIN), (isize::MIN as i128, isize::MIN as i128));
+    assert_eq!(widen(-1_i8), (-1, -1));
+
+    assert_eq!(i16::lossless_from(u8::MAX), 255);
+    assert_eq!(i64::upcast_from(u32::MAX), u32::MAX as i64);
+    assert_eq!(f32::lossless_from(u16::MAX), 65535.0);
+    assert_eq!(f64::upcast_from(u32::MAX), 4294967295.0);
+    assert_eq!(f64::lossless_from(i32::MIN), -2147483648.0);
+    assert_eq!(u64::lossless_from(usize::MAX), usize::MAX as u64);
+    assert_eq!(isize::lossless_from(u8::MAX), 255);
+    assert_eq!(
+        f64::lossless_from(f32::MIN_POSITIVE),
+        f32::MIN_POSITIVE as f64
+    );
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn test_lossless_half() {
+    for x in u8::MIN..=u8::MAX {
+        assert_eq!(f32::lossless_from(half::bf16::lossless_from(x)), x as f32);
+        assert_eq!(half::f16::upcast_from(x).to_f32(), x as f32);
+    }
+    for x in i8::MIN..=i8::MAX {
+        assert_eq!(f64::lossless_from(half::f16::lossless_from(x)), x as f64);
+        assert_eq!(half::bf16::upcast_from(x).to_f32(), x as f32);
+    }
+}
@@ -0,0 +1,86 @@
+use common_traits::*;
+use core::cmp::Ordering;
+
+/// Counts the elements of `data` greater than `threshold`.
+fn count_greater<T: NumCmp<U> + Copy, U: Copy>(data: &[T], threshold: U) -> usize {
+    data.iter().filter(|&&x| x.num_gt(threshold)).count()
+}
+
+#[test]
+fn test_num_cmp_ints() {
+    assert_eq!((-1_i64).num_cmp(u64::MAX), Some(Ordering::Less));
+    assert_eq!(u64::MAX.num_cmp(-1_i64), Some(Ordering::Greater));
+    assert!(u128::MAX.num_gt(i128::MAX));
+    assert!(i128::MIN.num_lt(0_u8));
+    assert!(255_u8.num_eq(255_i64));
+    assert!((-128_i8).num_eq(-128_isize));
+    assert!(300_u16.num_ne(44_u8));
+    assert!(5_usize.num_le(5_i8));
+    assert!(5_usize.num_ge(5_i8));
+}
+
+#[test]
+fn test_num_cmp_int_float() {
+    let two_53 = 1_u64 << 53;
+    // 2^53 + 1 is not representable as an f64
+    assert!((two_53 + 1).num_gt(two_53 as f64));
+    assert!((two_53 as f64).num_lt(two_53 + 1));
+    assert!(two_53.num_eq(two_53 as f64));
+    assert!(u64::MAX.num_lt(u64::MAX as f64));
+    assert!(i64::MIN.num_eq(i64::MIN as f64));
+    assert!((i64::MIN + 1).num_gt(i64::MIN as f64));
+
+    assert!(i128::MAX.num_lt(f32::MAX));
+    assert!(i128::MIN.num_eq(-(2.0_f32.powi(127))));
+    assert!(u128::MAX.num_lt(f32::INFINITY));
+    assert!(u128::MAX.num_lt(2.0_f64.powi(128)));
+    assert!(u128::MAX.num_gt(2.0_f64.powi(127)));
+    assert!(i8::MIN.num_gt(f64::NEG_INFINITY));
+
+    assert!(0_i32.num_eq(-0.0_f64));
+    assert!(0_u32.num_eq(0.0_f32));
+    assert!(1_i32.num_lt(1.5_f32));
+    assert!(2_i32.num_gt(1.5_f32));
+    assert!((-1_i32).num_gt(-1.5_f32));
+    assert!((-2_i32).num_lt(-1.5_f32));
+    assert!((-1_i32).num_lt(0.5_f32));
+    assert!(1_u32.num_gt(-0.5_f32));
+    assert!(0_u32.num_lt(1E-300_f64));
+    assert!(0_i32.num_gt(-1E-300_f64));
+    assert!(1.5_f64.num_gt(1_u8));
+}
+
+#[test]
+fn test_num_cmp_nan() {
+    assert_eq!(f64::NAN.num_cmp(1_u8), None);
+    assert_eq!(1_u8.num_cmp(f32::NAN), None);
+    assert_eq!(f32::NAN.num_cmp(f64::NAN), None);
+    assert!(!f64::NAN.num_eq(f64::NAN));
+    assert!(f64::NAN.num_ne(f64::NAN));
+    assert!(!f64::NAN.num_lt(0_i32));
+    assert!(!f64::NAN.num_ge(0_i32));
+}
+
+#[test]
+fn test_num_cmp_floats() {
+    assert!(0.1_f32.num_gt(0.1_f64));
+    assert!(0.5_f32.num_eq(0.5_f64));
+    assert!((-0.0_f32).num_eq(0.0_f64));
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn test_num_cmp_half() {
+    use half::{bf16, f16};
+    assert!(f16::from_f32(0.1).num_ne(bf16::from_f32(0.1)));
+    assert!(f16::MAX.num_eq(65504_u32));
+    assert!(bf16::MAX.num_lt(u128::MAX));
+    assert!(bf16::INFINITY.num_gt(u128::MAX));
+}
+
+#[test]
+fn test_num_cmp_generic() {
+    let data = [u64::MAX, (1 << 53) + 1, 1 << 53, 0];
+    assert_eq!(count_greater(&data, (1_u64 << 53) as f64), 2);
+    assert_eq!(count_greater(&data, -1_i8), 4);
+}
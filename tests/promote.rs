@@ -0,0 +1,76 @@
+use common_traits::*;
+use core::any::TypeId;
+
+fn promoted<A: Promote<B>, B>() -> TypeId
+where
+    A::Output: 'static,
+{
+    TypeId::of::<A::Output>()
+}
+
+#[test]
+fn test_promote_types() {
+    assert_eq!(promoted::<u8, i8>(), TypeId::of::<i16>());
+    assert_eq!(promoted::<i8, u8>(), TypeId::of::<i16>());
+    assert_eq!(promoted::<u32, f32>(), TypeId::of::<f64>());
+    assert_eq!(promoted::<u64, i64>(), TypeId::of::<i128>());
+    assert_eq!(promoted::<u16, u64>(), TypeId::of::<u64>());
+    assert_eq!(promoted::<f32, f32>(), TypeId::of::<f32>());
+    assert_eq!(promoted::<usize, u8>(), TypeId::of::<usize>());
+    assert_eq!(promoted::<isize, u8>(), TypeId::of::<isize>());
+    assert_eq!(promoted::<u64, isize>(), TypeId::of::<i128>());
+    assert_eq!(promoted::<usize, i128>(), TypeId::of::<i128>());
+}
+
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn test_promote_pointer_width() {
+    assert_eq!(promoted::<u16, isize>(), TypeId::of::<isize>());
+    assert_eq!(promoted::<isize, u32>(), TypeId::of::<isize>());
+    assert_eq!(promoted::<usize, i8>(), TypeId::of::<i128>());
+    assert_eq!(promoted::<i64, usize>(), TypeId::of::<i128>());
+    assert_eq!(promoted::<usize, isize>(), TypeId::of::<i128>());
+    assert_eq!(usize::MAX.promote_add(isize::MIN), i64::MAX as i128);
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn test_promote_half() {
+    use half::{bf16, f16};
+    assert_eq!(promoted::<f16, bf16>(), TypeId::of::<f32>());
+    assert_eq!(promoted::<u8, bf16>(), TypeId::of::<bf16>());
+    assert_eq!(
+        f16::from_f32(0.5).promote_add(bf16::from_f32(0.25)),
+        0.75_f32
+    );
+}
+
+#[test]
+fn test_promote_ops() {
+    assert_eq!(u8::MAX.promote_add(i8::MAX), 382_i16);
+    assert_eq!(u8::MAX.promote_mul(i8::MIN), -32640_i16);
+    assert_eq!(u64::MAX.promote_mul(-1_i64), -(u64::MAX as i128));
+    assert_eq!(u32::MAX.promote_add(0.5_f32), u32::MAX as f64 + 0.5);
+    assert_eq!(<i8 as Promote<u8>>::promote(-3), -3_i16);
+}
+
+#[test]
+fn test_promote_ops_rounding() {
+    // The promoted type represents exactly the operands, not their sum
+    assert_eq!(16777216_u32.promote_add(1.0_f32), 16777217.0_f64);
+    assert_eq!(16777216_f32.promote_add(1.0_f32), 16777216.0_f32);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "overflow")]
+fn test_promote_add_overflow() {
+    let _ = core::hint::black_box(200_u8).promote_add(100_u8);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "overflow")]
+fn test_promote_mul_overflow() {
+    let _ = core::hint::black_box(u8::MAX).promote_mul(u16::MAX);
+}
@@ -0,0 +1,119 @@
+use common_traits::{Atomic, IntoAtomic, SeqLockAtomic, impl_into_seq_lock_atomic};
+use core::sync::atomic::Ordering;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct Record {
+    id: u64,
+    score: f64,
+    count: u32,
+}
+
+impl_into_seq_lock_atomic!(Record);
+
+#[test]
+fn test_seq_lock_atomic() {
+    let atomic: SeqLockAtomic<[f64; 4]> = [1.0, 2.0, 3.0, 4.0].to_atomic();
+    assert_eq!(atomic.load(Ordering::SeqCst), [1.0, 2.0, 3.0, 4.0]);
+    atomic.store([0.0; 4], Ordering::Release);
+    assert_eq!(atomic.swap([5.0; 4], Ordering::AcqRel), [0.0; 4]);
+    assert_eq!(
+        atomic.compare_exchange([0.0; 4], [6.0; 4], Ordering::AcqRel, Ordering::Acquire),
+        Err([5.0; 4])
+    );
+    assert_eq!(
+        atomic.compare_exchange([5.0; 4], [6.0; 4], Ordering::AcqRel, Ordering::Acquire),
+        Ok([5.0; 4])
+    );
+    assert_eq!(
+        atomic.fetch_update(Ordering::AcqRel, Ordering::Acquire, |_| None),
+        Err([6.0; 4])
+    );
+    assert_eq!(
+        atomic.fetch_update(Ordering::AcqRel, Ordering::Acquire, |x| Some(
+            x.map(|v| v + 1.0)
+        )),
+        Ok([6.0; 4])
+    );
+    assert_eq!(atomic.into_inner(), [7.0; 4]);
+
+    let mut atomics = <[u8; 3]>::into_atomic_array([[1; 3], [2; 3]]);
+    <[u8; 3]>::get_mut_array(&mut atomics)[1] = [3; 3];
+    assert_eq!(<[u8; 3]>::from_atomic_array(atomics), [[1; 3], [3; 3]]);
+
+    let mut values = [[0_u16; 5]; 4];
+    <[u16; 5]>::from_mut_slice(&mut values)[2].store([9; 5], Ordering::Relaxed);
+    assert_eq!(values[2], [9; 5]);
+}
+
+#[test]
+fn test_seq_lock_atomic_record() {
+    let record = Record::default().to_atomic();
+    assert_eq!(format!("{:?}", record), format!("{:?}", Record::default()));
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..1000 {
+                    record
+                        .fetch_update(Ordering::AcqRel, Ordering::Acquire, |r| {
+                            Some(Record {
+                                id: r.id + 2,
+                                score: r.score + 0.5,
+                                count: r.count + 1,
+                            })
+                        })
+                        .unwrap();
+                }
+            });
+        }
+    });
+    assert_eq!(
+        record.load(Ordering::Acquire),
+        Record {
+            id: 8000,
+            score: 2000.0,
+            count: 4000
+        }
+    );
+}
+
+#[test]
+fn test_seq_lock_atomic_no_torn_reads() {
+    let atomic = SeqLockAtomic::<[u64; 4]>::default();
+    std::thread::scope(|s| {
+        for t in 0..2 {
+            let atomic = &atomic;
+            s.spawn(move || {
+                for i in 0..10_000_u64 {
+                    atomic.store([i * 2 + t; 4], Ordering::Release);
+                }
+            });
+        }
+        for _ in 0..2 {
+            s.spawn(|| {
+                for _ in 0..10_000 {
+                    let value = atomic.load(Ordering::Acquire);
+                    assert!(value.iter().all(|&x| x == value[0]), "{:?}", value);
+                }
+            });
+        }
+    });
+}
+
+/// Accepts any atomic, as generic containers in client code do.
+fn increment<A: Atomic>(atomic: &A, f: impl Fn(A::NonAtomicType) -> A::NonAtomicType) {
+    assert!(
+        atomic
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |x| Some(f(x)))
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_seq_lock_atomic_generic() {
+    let atomic = SeqLockAtomic::new([1_i32, 2, 3]);
+    increment(&atomic, |x| x.map(|v| v * 10));
+    assert_eq!(atomic.load(Ordering::Relaxed), [10, 20, 30]);
+    let atomic = core::sync::atomic::AtomicU32::new(1);
+    increment(&atomic, |x| x + 1);
+    assert_eq!(atomic.load(Ordering::Relaxed), 2);
+}
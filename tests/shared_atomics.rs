@@ -0,0 +1,115 @@
+#![cfg(all(feature = "std", any(target_os = "linux", target_os = "android")))]
+
+use common_traits::sync::{AtomicU32, AtomicU64};
+use common_traits::*;
+use core::num::NonZeroU16;
+use core::sync::atomic::Ordering;
+use std::fs::{File, OpenOptions};
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+fn temp_file(name: &str, len: u64) -> (PathBuf, File) {
+    let path = std::env::temp_dir().join(format!("common_traits_{}_{}", name, std::process::id()));
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(&path)
+        .unwrap();
+    file.set_len(len).unwrap();
+    (path, file)
+}
+
+#[test]
+fn test_as_ptr_from_ptr() {
+    let atomic = AtomicF64::new(1.5);
+    let ptr = atomic.as_ptr();
+    assert_eq!(unsafe { *ptr }, 1.5);
+    let alias = unsafe { AtomicF64::from_ptr(ptr) };
+    alias.fetch_add(1.0, Ordering::Relaxed);
+    assert_eq!(atomic.load(Ordering::Relaxed), 2.5);
+
+    let mut values = [1_u32, 2, 3];
+    let atomic = unsafe { <AtomicU32 as Atomic>::from_ptr(&mut values[1]) };
+    atomic.fetch_add(5, Ordering::Relaxed);
+    assert_eq!(values, [1, 7, 3]);
+
+    let mut value = Some(NonZeroU16::new(3).unwrap());
+    let atomic = unsafe { AtomicOptionNonZero::<NonZeroU16>::from_ptr(&mut value) };
+    atomic.store(None, Ordering::Relaxed);
+    assert_eq!(atomic.as_ptr(), &mut value as *mut _);
+    assert_eq!(value, None);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "not aligned")]
+fn test_from_ptr_unaligned() {
+    let mut values = [0_u64; 2];
+    let ptr = unsafe { values.as_mut_ptr().cast::<u8>().add(4) }.cast::<u64>();
+    let _ = unsafe { <AtomicU64 as Atomic>::from_ptr(ptr) };
+}
+
+#[test]
+fn test_anonymous() {
+    let counters = SharedAtomics::<AtomicU64>::anonymous(1000).unwrap();
+    assert_eq!(counters.len(), 1000);
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for counter in counters.iter() {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+        }
+    });
+    assert!(counters.iter().all(|c| c.load(Ordering::Relaxed) == 4));
+
+    let floats = SharedAtomics::<AtomicF32>::anonymous(3).unwrap();
+    assert!(floats.iter().all(|f| f.load(Ordering::Relaxed) == 0.0));
+
+    assert!(SharedAtomics::<AtomicU64>::anonymous(0).unwrap().is_empty());
+    assert_eq!(
+        SharedAtomics::<AtomicU64>::anonymous(usize::MAX)
+            .unwrap_err()
+            .kind(),
+        ErrorKind::InvalidInput
+    );
+}
+
+#[test]
+fn test_from_file() {
+    let (path, file) = temp_file("from_file", 4096);
+    let first = unsafe { SharedAtomics::<AtomicF64>::from_file(&file, 0, 512) }.unwrap();
+    let second = unsafe { SharedAtomics::<AtomicF64>::from_file(&file, 0, 512) }.unwrap();
+    assert_eq!(first.len(), 512);
+    first[7].fetch_add(0.25, Ordering::Relaxed);
+    second[7].fetch_add(0.5, Ordering::Relaxed);
+    assert_eq!(first[7].load(Ordering::Relaxed), 0.75);
+    drop(first);
+    drop(second);
+
+    let bytes = std::fs::read(&path).unwrap();
+    let value = f64::from_ne_bytes(bytes[7 * 8..8 * 8].try_into().unwrap());
+    assert_eq!(value, 0.75);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_from_file_validation() {
+    let (path, file) = temp_file("validation", 4096);
+    // past the end of the file
+    let err = unsafe { SharedAtomics::<AtomicU64>::from_file(&file, 0, 513) }.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    // offset not page aligned
+    let err = unsafe { SharedAtomics::<AtomicU64>::from_file(&file, 8, 1) }.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    // overflowing size
+    let err = unsafe { SharedAtomics::<AtomicU64>::from_file(&file, 0, usize::MAX) }.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+    let all = unsafe { SharedAtomics::<AtomicU64>::from_file(&file, 0, 512) }.unwrap();
+    assert_eq!(all.len(), 512);
+    std::fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,259 @@
+#![cfg(any(feature = "alloc", feature = "std"))]
+
+use common_traits::sync::{AtomicU8, AtomicU16, AtomicU32, AtomicU64};
+use common_traits::*;
+use core::sync::atomic::Ordering;
+
+/// FNV-1a, with the seed xored into the offset basis.
+struct Fnv(u64);
+
+impl SeedableHasher for Fnv {
+    type Seed = u64;
+    fn new(seed: u64) -> Self {
+        Self(0xCBF2_9CE4_8422_2325 ^ seed)
+    }
+}
+
+impl Hasher for Fnv {
+    type Result = u64;
+    fn finish(&self) -> u64 {
+        self.0
+    }
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ byte as u64).wrapping_mul(0x100_0000_01B3);
+        }
+    }
+}
+
+/// A deliberately weak hasher with a 16-bit output.
+struct Sum(u16);
+
+impl SeedableHasher for Sum {
+    type Seed = u16;
+    fn new(seed: u16) -> Self {
+        Self(seed)
+    }
+}
+
+impl Hasher for Sum {
+    type Result = u16;
+    fn finish(&self) -> u16 {
+        self.0
+    }
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = self.0.wrapping_mul(31).wrapping_add(byte as u16);
+        }
+    }
+}
+
+const R: Ordering = Ordering::Relaxed;
+
+#[test]
+fn test_bloom_filter() {
+    let filter = BloomFilter::<Fnv, AtomicU32>::new(10_000, 7, 0);
+    let mut already = 0;
+    for i in 0..1000_u64 {
+        already += filter.insert(&i, R) as usize;
+    }
+    assert!(already < 10);
+    assert!(filter.insert(&0_u64, R));
+    assert!((0..1000_u64).all(|i| filter.contains(&i, R)));
+    // the false-positive rate for these parameters is about 0.8%
+    let false_positives = (1000..11_000_u64).filter(|i| filter.contains(i, R)).count();
+    assert!(false_positives < 300, "{false_positives}");
+
+    filter.clear(R);
+    assert!(!(0..1000_u64).any(|i| filter.contains(&i, R)));
+}
+
+#[test]
+fn test_bloom_filter_concurrent_merge_and_bytes() {
+    let first = BloomFilter::<Fnv>::new(1 << 14, 4, 7);
+    let second = BloomFilter::<Fnv>::new(1 << 14, 4, 7);
+    std::thread::scope(|s| {
+        for t in 0..4 {
+            let filter = if t % 2 == 0 { &first } else { &second };
+            s.spawn(move || {
+                for i in 0..500 {
+                    filter.insert(&format!("{t}-{i}")[..], R);
+                }
+            });
+        }
+    });
+    first.merge(&second, R, R);
+    for t in 0..4 {
+        assert!((0..500).all(|i| first.contains(&format!("{t}-{i}")[..], R)));
+    }
+
+    let bytes = first.to_le_bytes(R);
+    assert_eq!(bytes.len(), (1 << 14) / 8);
+    let copy = BloomFilter::<Fnv>::from_le_bytes(1 << 14, 4, 7, &bytes).unwrap();
+    assert_eq!(copy.to_le_bytes(R), bytes);
+    assert!(BloomFilter::<Fnv>::from_le_bytes(1 << 14, 4, 7, &bytes[1..]).is_err());
+}
+
+#[test]
+#[should_panic(expected = "different parameters or seeds")]
+fn test_bloom_filter_merge_seeds() {
+    let first = BloomFilter::<Fnv>::new(64, 2, 0);
+    let second = BloomFilter::<Fnv>::new(64, 2, 1);
+    first.merge(&second, R, R);
+}
+
+#[test]
+fn test_weak_hasher() {
+    // finalization spreads 16-bit hashes over all bits
+    let filter = BloomFilter::<Sum, AtomicU8>::new(1 << 12, 3, 0);
+    for i in 0..100_u32 {
+        filter.insert(&i, R);
+    }
+    assert!((0..100_u32).all(|i| filter.contains(&i, R)));
+    let set: usize = filter
+        .to_le_bytes(R)
+        .iter()
+        .map(|b| b.count_ones() as usize)
+        .sum();
+    assert!(set > 250, "{set}");
+}
+
+#[test]
+fn test_counting_bloom_filter() {
+    let filter = CountingBloomFilter::<Fnv, AtomicU8>::new(4096, 4, 0);
+    for i in 0..200_u64 {
+        filter.insert(&i, R, R);
+    }
+    filter.insert(&5_u64, R, R);
+    assert!((0..200_u64).all(|i| filter.contains(&i, R)));
+    for i in 0..100_u64 {
+        filter.remove(&i, R, R);
+    }
+    assert!((100..200_u64).all(|i| filter.contains(&i, R)));
+    assert!(filter.contains(&5_u64, R));
+    filter.remove(&5_u64, R, R);
+    let still = (0..100_u64).filter(|i| filter.contains(i, R)).count();
+    assert!(still < 5, "{still}");
+
+    // saturated counters stick
+    let small = CountingBloomFilter::<Fnv, AtomicU8>::new(16, 1, 0);
+    for _ in 0..300 {
+        small.insert("x", R, R);
+    }
+    assert_eq!(small.to_le_bytes(R).into_iter().max(), Some(u8::MAX));
+    small.remove("x", R, R);
+    assert!(small.contains("x", R));
+
+    let copy = CountingBloomFilter::<Fnv, AtomicU8>::from_le_bytes(16, 1, 0, &small.to_le_bytes(R))
+        .unwrap();
+    copy.merge(&small, R, R);
+    assert_eq!(copy.to_le_bytes(R), small.to_le_bytes(R));
+}
+
+#[test]
+fn test_count_min_sketch() {
+    let sketch = CountMinSketch::<Fnv, AtomicU32>::new(272, 5, 0);
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for i in 0..1000_u64 {
+                    sketch.insert(&(i % 100), (i % 100) as u32, R);
+                }
+            });
+        }
+    });
+    // the total count is 4 · 10 · 4950, and ε = e / 272 ≈ 1%
+    for i in 0..100_u64 {
+        let estimate = sketch.estimate(&i, R);
+        assert!(estimate >= 40 * i as u32);
+        assert!(estimate <= 40 * i as u32 + 2000, "{i} {estimate}");
+    }
+
+    let other = CountMinSketch::<Fnv, AtomicU32>::new(272, 5, 0);
+    other.insert("a", 10, R);
+    sketch.merge(&other, R, R);
+    assert!(sketch.estimate("a", R) >= 10);
+
+    let bytes = sketch.to_le_bytes(R);
+    assert_eq!(bytes.len(), 272 * 5 * 4);
+    let copy = CountMinSketch::<Fnv, AtomicU32>::from_le_bytes(272, 5, 0, &bytes).unwrap();
+    assert_eq!(copy.estimate(&99_u64, R), sketch.estimate(&99_u64, R));
+    assert!(CountMinSketch::<Fnv, AtomicU32>::from_le_bytes(272, 4, 0, &bytes).is_err());
+}
+
+#[test]
+fn test_hyper_log_log() {
+    let counter = HyperLogLog::<Fnv>::new(12, 0);
+    std::thread::scope(|s| {
+        for t in 0..4_u64 {
+            let counter = &counter;
+            s.spawn(move || {
+                for i in 0..50_000_u64 {
+                    // every item is inserted twice
+                    counter.insert(&((t / 2) * 50_000 + i), R);
+                }
+            });
+        }
+    });
+
+    #[cfg(feature = "std")]
+    {
+        // the standard error is about 1.6%
+        let estimate = counter.estimate(R);
+        assert!((estimate - 100_000.0).abs() < 6_000.0, "{estimate}");
+        let small = HyperLogLog::<Fnv, AtomicU16>::new(10, 0);
+        for i in 0..100_u64 {
+            small.insert(&i, R);
+        }
+        let estimate = small.estimate(R);
+        assert!((estimate - 100.0).abs() < 10.0, "{estimate}");
+    }
+
+    let first = HyperLogLog::<Fnv, AtomicU64>::new(8, 1);
+    let second = HyperLogLog::<Fnv, AtomicU64>::new(8, 1);
+    for i in 0..1000_u64 {
+        first.insert(&i, R);
+        second.insert(&(i + 1000), R);
+    }
+    first.merge(&second, R, R);
+    let bytes = first.to_le_bytes(R);
+    assert_eq!(bytes.len(), 256 * 8);
+    let copy = HyperLogLog::<Fnv, AtomicU64>::from_le_bytes(8, 1, &bytes).unwrap();
+    assert_eq!(copy.to_le_bytes(R), bytes);
+    #[cfg(feature = "std")]
+    {
+        let estimate = copy.estimate(R);
+        assert!((estimate - 2000.0).abs() < 400.0, "{estimate}");
+    }
+    copy.clear(R);
+    assert!(copy.to_le_bytes(R).into_iter().all(|b| b == 0));
+
+    // registers cannot exceed 65 - precision
+    let mut bytes = vec![0_u8; 256];
+    bytes[3] = 57;
+    assert!(HyperLogLog::<Fnv>::from_le_bytes(8, 0, &bytes).is_ok());
+    bytes[3] = 58;
+    assert!(HyperLogLog::<Fnv>::from_le_bytes(8, 0, &bytes).is_err());
+}
+
+#[test]
+fn test_hash_primitives() {
+    fn fnv<T: Hash + ?Sized>(item: &T) -> u64 {
+        let mut hasher = Fnv::new(0);
+        item.hash(&mut hasher);
+        hasher.finish()
+    }
+    let mut bytes = Fnv::new(0);
+    bytes.write(&[1, 0, 0, 0]);
+    assert_eq!(fnv(&1_u32), bytes.finish());
+    let mut bytes = Fnv::new(0);
+    bytes.write(&[2, 0, 0, 0, 0, 0, 0, 0]);
+    bytes.write(&[3, 0]);
+    bytes.write(&[4, 0]);
+    assert_eq!(fnv(&[3_u16, 4]), bytes.finish());
+    assert_ne!(fnv("ab"), fnv("a"));
+    assert_ne!(fnv(&["a", "b"]), fnv(&["ab", ""]));
+    assert_eq!(fnv(&&5_i64), fnv(&5_i64));
+    assert_ne!(fnv(&true), fnv(&false));
+    assert_eq!(fnv(&'a'), fnv(&('a' as u32)));
+}
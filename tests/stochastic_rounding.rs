@@ -0,0 +1,109 @@
+#![cfg(feature = "half")]
+use common_traits::*;
+use half::{bf16, f16};
+
+struct Xorshift64(u64);
+
+impl Rng for Xorshift64 {
+    type Seed = u64;
+    fn new(seed: u64) -> Self {
+        Self(seed.saturating_add(1))
+    }
+}
+
+impl RngNext<u32> for Xorshift64 {
+    fn next_inner(&mut self) -> u32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 32) as u32
+    }
+}
+
+fn test_unbiased<S, D>(values: &[S])
+where
+    S: Float + To<f64>,
+    D: StochasticRoundFrom<S> + DowncastableFrom<S> + To<f64> + Copy + PartialEq,
+{
+    let mut rng = Xorshift64::new(0);
+    for &value in values {
+        let nearest = D::downcast_from(value);
+        let mut sum = 0.0;
+        let samples = 100_000;
+        let mut outcomes = [nearest; 2];
+        let mut n_outcomes = 1;
+        for _ in 0..samples {
+            let rounded = D::stochastic_round_from(value, &mut rng);
+            if !outcomes[..n_outcomes].contains(&rounded) {
+                assert!(n_outcomes < 2, "more than two outcomes");
+                outcomes[n_outcomes] = rounded;
+                n_outcomes += 1;
+            }
+            sum += rounded.to();
+        }
+        let value: f64 = value.to();
+        let mean = sum / samples as f64;
+        assert!(
+            (mean - value).abs() <= value.abs() * 1E-4 + 1E-9,
+            "value {} mean {}",
+            value,
+            mean,
+        );
+    }
+}
+
+#[test]
+fn test_stochastic_rounding_unbiased() {
+    let values_f32 = [1.0 + f32::EPSILON, 1.001, -3.3, 1e-6, 0.1, 12345.678, 1e-40];
+    let values_f64 = [1.0 + f64::EPSILON * 1000.0, -3.3, 1e-6, 0.1, 12345.678];
+    test_unbiased::<f32, bf16>(&values_f32);
+    test_unbiased::<f32, f16>(&values_f32);
+    test_unbiased::<f64, bf16>(&values_f64);
+    test_unbiased::<f64, f16>(&values_f64);
+}
+
+#[test]
+fn test_stochastic_rounding_special() {
+    let mut rng = Xorshift64::new(0);
+    for _ in 0..1000 {
+        assert_eq!(
+            bf16::stochastic_round_from(1.5_f32, &mut rng),
+            bf16::from_f32(1.5)
+        );
+        assert_eq!(
+            f16::stochastic_round_from(-2.0_f64, &mut rng),
+            f16::from_f32(-2.0)
+        );
+        assert!(bf16::stochastic_round_from(f32::NAN, &mut rng).is_nan());
+        assert!(f16::stochastic_round_from(f32::NAN, &mut rng).is_nan());
+        assert_eq!(
+            bf16::stochastic_round_from(f32::INFINITY, &mut rng),
+            bf16::INFINITY
+        );
+        assert_eq!(
+            f16::stochastic_round_from(f64::NEG_INFINITY, &mut rng),
+            f16::NEG_INFINITY
+        );
+        assert_eq!(
+            f16::stochastic_round_from(1e10_f32, &mut rng),
+            f16::INFINITY
+        );
+        let x = f16::stochastic_round_from(65510.0_f32, &mut rng);
+        assert!(x == f16::MAX || x == f16::INFINITY);
+        let x = bf16::stochastic_round_from(-f32::MAX, &mut rng);
+        assert!(x == bf16::MIN || x == bf16::NEG_INFINITY);
+    }
+}
+
+#[test]
+fn test_stochastic_rounding_sequence() {
+    let mut rng = Xorshift64::new(0);
+    let src: Vec<f32> = (0..100).map(|i| i as f32 / 7.0).collect();
+    let mut dst = vec![bf16::ZERO; 100];
+    bf16::stochastic_round_sequence(&src, &mut dst, &mut rng).unwrap();
+    for (x, y) in src.iter().zip(dst.iter()) {
+        assert!((y.to_f32() - x).abs() <= x * 2.0_f32.powi(-7));
+    }
+    let mut short = vec![f16::ZERO; 10];
+    assert!(f16::stochastic_round_sequence(&src, &mut short, &mut rng).is_err());
+}
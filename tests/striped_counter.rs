@@ -0,0 +1,78 @@
+use common_traits::*;
+use core::sync::atomic::{AtomicI32, AtomicU64, AtomicUsize, Ordering};
+
+#[test]
+fn test_striped_counter() {
+    let counter = StripedCounter::<AtomicUsize, 4>::new(10);
+    assert_eq!(counter.num_shards(), 4);
+    assert_eq!(counter.load(Ordering::Relaxed), 10);
+    assert_eq!(counter.fetch_add_with_hint(5, 1, Ordering::Relaxed), 0);
+    assert_eq!(counter.fetch_add_with_hint(5, 5, Ordering::Relaxed), 5);
+    assert_eq!(
+        counter.fetch_add(1, Ordering::Relaxed),
+        counter.fetch_sub(1, Ordering::Relaxed) - 1
+    );
+    assert_eq!(counter.load(Ordering::Relaxed), 20);
+    assert_eq!(format!("{:?}", counter), "20");
+    assert_eq!(counter.swap(3, Ordering::AcqRel), 20);
+    assert_eq!(counter.load(Ordering::Acquire), 3);
+    assert_eq!(counter.reset(Ordering::Relaxed), 3);
+    assert_eq!(counter.into_inner(), 0);
+}
+
+fn add_all<C: AtomicCounter<Value = u64>>(counter: &C, values: &[u64]) -> u64 {
+    for &value in values {
+        counter.counter_fetch_add(value, Ordering::Relaxed);
+    }
+    counter.counter_fetch_sub(1, Ordering::Relaxed);
+    counter.counter_swap(0, Ordering::Relaxed)
+}
+
+#[test]
+fn test_atomic_counter() {
+    let plain = AtomicU64::new(0);
+    let striped = StripedCounter::<AtomicU64, 4>::default();
+    assert_eq!(add_all(&plain, &[1, 2, 3]), 5);
+    assert_eq!(add_all(&striped, &[1, 2, 3]), 5);
+    assert_eq!(plain.counter_load(Ordering::Relaxed), 0);
+    assert_eq!(striped.counter_load(Ordering::Relaxed), 0);
+
+    let float = AtomicF64::new(1.5);
+    assert_eq!(float.counter_fetch_add(1.0, Ordering::Relaxed), 1.5);
+    assert_eq!(float.fetch_add(1.0, Ordering::Relaxed), 2.5);
+}
+
+#[test]
+fn test_striped_counter_wrapping() {
+    // Shards can wrap around even if the total does not
+    let counter = StripedCounter::<AtomicU64, 2>::default();
+    counter.fetch_add_with_hint(5, 0, Ordering::Relaxed);
+    counter.fetch_sub_with_hint(3, 1, Ordering::Relaxed);
+    assert_eq!(counter.load(Ordering::Relaxed), 2);
+
+    let counter = StripedCounter::<AtomicI32, 3>::new(i32::MAX);
+    counter.fetch_add_with_hint(1, 2, Ordering::Relaxed);
+    assert_eq!(counter.into_inner(), i32::MIN);
+}
+
+#[cfg(not(miri))]
+#[test]
+fn test_striped_counter_concurrent() {
+    let counter = StripedCounter::<AtomicF64>::default();
+    let ints = StripedCounter::<AtomicUsize, 8>::default();
+    std::thread::scope(|s| {
+        for t in 0..16 {
+            let counter = &counter;
+            let ints = &ints;
+            s.spawn(move || {
+                for _ in 0..1000 {
+                    counter.fetch_add(0.25, Ordering::Relaxed);
+                    ints.fetch_add_with_hint(2, t, Ordering::Relaxed);
+                    ints.fetch_sub(1, Ordering::Relaxed);
+                }
+            });
+        }
+    });
+    assert_eq!(counter.load(Ordering::Relaxed), 4000.0);
+    assert_eq!(ints.into_inner(), 16000);
+}